@@ -852,27 +852,42 @@ impl<'a, K, V> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge
     /// Inserts a new key/value pair between the key/value pairs to the right and left of
     /// this edge. This method splits the node if there isn't enough room.
     ///
-    /// The returned pointer points to the inserted value.
-    pub fn insert(mut self, key: K, val: V) -> (InsertResult<'a, K, V, marker::Leaf>, *mut V) {
+    /// The returned handle points to the inserted pair. Leaf nodes never move, so it
+    /// remains valid while the caller inserts the split off pair into the ancestors.
+    pub fn insert(
+        mut self,
+        key: K,
+        val: V,
+    ) -> (
+        InsertResult<'a, K, V, marker::Leaf>,
+        Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV>,
+    ) {
         if self.node.len() < CAPACITY {
-            let ptr = self.insert_fit(key, val);
+            self.insert_fit(key, val);
+            // We duplicate the node here -- the caller uses at most one of the two handles.
+            let tracked = unsafe { Handle::new_kv(ptr::read(&self.node), self.idx) };
             let kv = unsafe { Handle::new_kv(self.node, self.idx) };
-            (InsertResult::Fit(kv), ptr)
+            (InsertResult::Fit(kv), tracked)
         } else {
             let middle = unsafe { Handle::new_kv(self.node, B) };
-            let (mut left, k, v, mut right) = middle.split();
-            let ptr = if self.idx <= B {
-                unsafe { Handle::new_edge(left.reborrow_mut(), self.idx).insert_fit(key, val) }
+            let (left, k, v, right) = middle.split();
+            let (node, idx) = if self.idx <= B {
+                (unsafe { ptr::read(&left) }, self.idx)
             } else {
-                unsafe {
-                    Handle::new_edge(
-                        right.as_mut().cast_unchecked::<marker::Leaf>(),
-                        self.idx - (B + 1),
-                    )
-                    .insert_fit(key, val)
-                }
+                // The new node is not linked into the tree yet, but it will be under the
+                // same root as `left`.
+                let node = NodeRef {
+                    height: 0,
+                    node: right.node.as_ptr(),
+                    root: left.root,
+                    _marker: PhantomData,
+                };
+                (node, self.idx - (B + 1))
             };
-            (InsertResult::Split(left, k, v, right), ptr)
+            let mut edge = unsafe { Handle::new_edge(node, idx) };
+            edge.insert_fit(key, val);
+            let tracked = unsafe { Handle::new_kv(edge.node, idx) };
+            (InsertResult::Split(left, k, v, right), tracked)
         }
     }
 }
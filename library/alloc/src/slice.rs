@@ -95,6 +95,16 @@ use crate::borrow::ToOwned;
 use crate::boxed::Box;
 use crate::vec::Vec;
 
+#[unstable(feature = "array_chunks", issue = "none")]
+pub use core::slice::ArrayChunks;
+#[unstable(feature = "array_windows", issue = "none")]
+pub use core::slice::ArrayWindows;
+#[unstable(feature = "slice_group_by", issue = "none")]
+pub use core::slice::{GroupBy, GroupByMut};
+#[unstable(feature = "byte_slice_lines", issue = "none")]
+pub use core::slice::ByteLines;
+#[unstable(feature = "byte_slice_pattern", issue = "none")]
+pub use core::slice::SplitPattern;
 #[stable(feature = "slice_get_slice", since = "1.28.0")]
 pub use core::slice::SliceIndex;
 #[stable(feature = "from_ref", since = "1.28.0")]
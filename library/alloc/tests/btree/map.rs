@@ -1286,6 +1286,131 @@ fn test_first_last_entry() {
     assert_eq!(a.last_entry().unwrap().key(), &1);
 }
 
+#[test]
+fn test_cursor() {
+    let map: BTreeMap<_, _> = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i * 2, i)).collect();
+
+    let mut cur = map.lower_bound(Unbounded);
+    assert_eq!(cur.key_value(), Some((&0, &0)));
+    assert_eq!(cur.peek_prev(), None);
+    cur.move_prev();
+    assert_eq!(cur.key(), None);
+    cur.move_prev();
+    assert_eq!(cur.key(), Some(&((MIN_INSERTS_HEIGHT_2 - 1) * 2)));
+
+    let mut cur = map.lower_bound(Included(&5));
+    assert_eq!(cur.key(), Some(&6));
+    assert_eq!(cur.peek_prev(), Some((&4, &2)));
+    for i in 3..MIN_INSERTS_HEIGHT_2 {
+        assert_eq!(cur.key_value(), Some((&(i * 2), &i)));
+        cur.move_next();
+    }
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_next(), Some((&0, &0)));
+
+    assert_eq!(map.lower_bound(Included(&6)).key(), Some(&6));
+    assert_eq!(map.lower_bound(Excluded(&6)).key(), Some(&8));
+    assert_eq!(map.upper_bound(Included(&6)).key(), Some(&6));
+    assert_eq!(map.upper_bound(Excluded(&6)).key(), Some(&4));
+    assert_eq!(map.upper_bound(Excluded(&0)).key(), None);
+    assert_eq!(map.upper_bound(Unbounded).value(), Some(&(MIN_INSERTS_HEIGHT_2 - 1)));
+
+    let empty = BTreeMap::<i32, i32>::new();
+    let mut cur = empty.lower_bound(Unbounded);
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut map: BTreeMap<_, _> = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i * 2, i)).collect();
+
+    let mut cur = map.upper_bound_mut(Included(&9));
+    assert_eq!(cur.key(), Some(&8));
+    *cur.value_mut().unwrap() += 100;
+    cur.move_next();
+    assert_eq!(cur.peek_prev(), Some((&8, &mut 104)));
+    assert_eq!(cur.key_value_mut(), Some((&10, &mut 5)));
+    assert_eq!(cur.as_cursor().peek_next(), Some((&12, &6)));
+    assert_eq!(map[&8], 104);
+}
+
+#[test]
+fn test_cursor_mut_insert() {
+    let mut map: BTreeMap<_, _> = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i * 3 + 2, i)).collect();
+
+    // Fill in around every element, forcing nodes at all levels to split underneath the cursor.
+    let mut cur = map.lower_bound_mut(Unbounded);
+    for i in 0..MIN_INSERTS_HEIGHT_2 {
+        assert_eq!(cur.key(), Some(&(i * 3 + 2)));
+        if i % 2 == 0 {
+            cur.insert_before(i * 3 + 1, i);
+            assert_eq!(cur.peek_prev().map(|(&k, _)| k), Some(i * 3 + 1));
+        } else {
+            cur.insert_after(i * 3 + 3, i);
+            cur.move_next();
+            assert_eq!(cur.key(), Some(&(i * 3 + 3)));
+        }
+        cur.move_next();
+    }
+    assert_eq!(cur.key(), None);
+    cur.insert_after(0, 0);
+    cur.insert_before(MIN_INSERTS_HEIGHT_2 * 3 + 1, 0);
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_next(), Some((&0, &mut 0)));
+    assert_eq!(cur.peek_prev(), Some((&(MIN_INSERTS_HEIGHT_2 * 3 + 1), &mut 0)));
+
+    let mut expected: Vec<_> = (0..MIN_INSERTS_HEIGHT_2)
+        .flat_map(|i| vec![i * 3 + 2, if i % 2 == 0 { i * 3 + 1 } else { i * 3 + 3 }])
+        .collect();
+    expected.push(0);
+    expected.push(MIN_INSERTS_HEIGHT_2 * 3 + 1);
+    expected.sort();
+    assert_eq!(map.len(), expected.len());
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+#[should_panic(expected = "key must be ordered below the next element")]
+fn test_cursor_mut_insert_after_unordered() {
+    let mut map: BTreeMap<_, _> = (0..5).map(|i| (i * 2, i)).collect();
+    map.lower_bound_mut(Included(&2)).insert_after(4, 0);
+}
+
+#[test]
+#[should_panic(expected = "key must be ordered below the current element")]
+fn test_cursor_mut_insert_before_unordered() {
+    let mut map: BTreeMap<_, _> = (0..5).map(|i| (i * 2, i)).collect();
+    map.lower_bound_mut(Included(&2)).insert_before(3, 0);
+}
+
+#[test]
+fn test_cursor_mut_remove() {
+    let mut map: BTreeMap<_, _> = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i)).collect();
+
+    // Remove every other element, causing nodes at all levels to underflow and merge.
+    let mut cur = map.lower_bound_mut(Unbounded);
+    for i in (0..MIN_INSERTS_HEIGHT_2).step_by(2) {
+        assert_eq!(cur.remove_current(), Some((i, i)));
+        cur.move_next();
+    }
+    assert_eq!(cur.remove_current(), None);
+    assert_eq!(map.len(), MIN_INSERTS_HEIGHT_2 / 2);
+    assert!(map.keys().copied().eq((1..MIN_INSERTS_HEIGHT_2).step_by(2)));
+
+    let mut cur = map.upper_bound_mut(Unbounded);
+    while let Some((k, _)) = cur.remove_current_and_move_back() {
+        assert_eq!(cur.key().map(|&p| p + 2), Some(k).filter(|&k| k > 1));
+    }
+    assert!(map.is_empty());
+
+    let mut cur = map.lower_bound_mut(Unbounded);
+    cur.insert_after(1, 1);
+    assert_eq!(cur.peek_next(), Some((&1, &mut 1)));
+    assert_eq!(map.len(), 1);
+}
+
 macro_rules! create_append_test {
     ($name:ident, $len:expr) => {
         #[test]
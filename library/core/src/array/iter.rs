@@ -70,6 +70,18 @@ impl<T, const N: usize> IntoIter<T, N> {
         Self { data, alive: 0..N }
     }
 
+    /// Creates an iterator over the elements of `data` in `alive`.
+    ///
+    /// # Safety
+    ///
+    /// The elements of `data` in `alive` must be initialized, and `alive`
+    /// must be in bounds of the array. The other elements are never read or
+    /// dropped.
+    pub(crate) unsafe fn new_unchecked(data: [MaybeUninit<T>; N], alive: Range<usize>) -> Self {
+        debug_assert!(alive.start <= alive.end && alive.end <= N);
+        Self { data, alive }
+    }
+
     /// Returns an immutable slice of all elements that have not been yielded
     /// yet.
     fn as_slice(&self) -> &[T] {
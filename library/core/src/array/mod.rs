@@ -12,6 +12,8 @@ use crate::convert::{Infallible, TryFrom};
 use crate::fmt;
 use crate::hash::{self, Hash};
 use crate::marker::Unsize;
+use crate::mem::{self, MaybeUninit};
+use crate::ptr;
 use crate::slice::{Iter, IterMut};
 
 mod iter;
@@ -19,6 +21,130 @@ mod iter;
 #[unstable(feature = "array_value_iter", issue = "65798")]
 pub use iter::IntoIter;
 
+/// Creates an array `[T; N]` where each element is `cb(i)`, with `i` the
+/// index of the element, or returns the first error `cb` returns.
+///
+/// The elements are created in order of their index. If `cb` returns an
+/// error, the elements created so far are dropped before the error is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_try_from_fn)]
+/// use std::array;
+///
+/// let array: Result<[usize; 4], ()> = array::try_from_fn(|i| Ok(i * 2));
+/// assert_eq!(array, Ok([0, 2, 4, 6]));
+///
+/// let array: Result<[u8; 4], _> = array::try_from_fn(|i| (i as u8).checked_mul(100).ok_or(i));
+/// assert_eq!(array, Err(3));
+/// ```
+#[unstable(feature = "array_try_from_fn", issue = "none")]
+pub fn try_from_fn<T, E, F, const N: usize>(mut cb: F) -> Result<[T; N], E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut array = MaybeUninit::uninit_array::<N>();
+    let mut guard = Guard { array: &mut array, initialized: 0 };
+    while guard.initialized < N {
+        let item = cb(guard.initialized)?;
+        // SAFETY: the array isn't full yet.
+        unsafe { guard.push_unchecked(item) };
+    }
+    mem::forget(guard);
+    // SAFETY: the loop above initialized all elements.
+    Ok(unsafe { array_assume_init(array) })
+}
+
+/// Pulls `N` items from `iter` and returns them as an array. If the iterator
+/// runs out before that, the items it did yield are returned in an
+/// [`IntoIter`] instead.
+pub(crate) fn iter_next_chunk<I, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], IntoIter<I::Item, N>>
+where
+    I: Iterator,
+{
+    let mut array = MaybeUninit::uninit_array::<N>();
+    let mut guard = Guard { array: &mut array, initialized: 0 };
+    while guard.initialized < N {
+        match iter.next() {
+            // SAFETY: the array isn't full yet.
+            Some(item) => unsafe { guard.push_unchecked(item) },
+            None => {
+                let alive = 0..guard.initialized;
+                mem::forget(guard);
+                // SAFETY: the loop initialized the elements in `alive`, and
+                // the iterator takes over dropping them.
+                return Err(unsafe { IntoIter::new_unchecked(array, alive) });
+            }
+        }
+    }
+    mem::forget(guard);
+    // SAFETY: the loop above initialized all elements.
+    Ok(unsafe { array_assume_init(array) })
+}
+
+/// Pulls `N` items from `iter` and returns them as an array.
+///
+/// # Safety
+///
+/// `iter` must yield at least `N` items.
+unsafe fn collect_into_array_unchecked<I, const N: usize>(iter: &mut I) -> [I::Item; N]
+where
+    I: Iterator,
+{
+    match iter_next_chunk(iter) {
+        Ok(array) => array,
+        // SAFETY: the caller promises that the iterator doesn't run out.
+        Err(_) => unsafe { crate::hint::unreachable_unchecked() },
+    }
+}
+
+/// Converts a fully initialized array of `MaybeUninit<T>` to an array of `T`.
+///
+/// # Safety
+///
+/// All elements of `array` must be initialized.
+unsafe fn array_assume_init<T, const N: usize>(array: [MaybeUninit<T>; N]) -> [T; N] {
+    // FIXME: use `mem::transmute` here, once it works with const generics.
+    // SAFETY: `MaybeUninit<T>` has the same layout as `T`, and the caller
+    // promises that all elements are initialized. `MaybeUninit` never drops
+    // its contents, so there is nothing left to forget in `array`.
+    unsafe { (&array as *const [MaybeUninit<T>; N] as *const [T; N]).read() }
+}
+
+/// Drops the initialized prefix of an array that is being filled in, if the
+/// code filling it in panics or returns early.
+struct Guard<'a, T, const N: usize> {
+    array: &'a mut [MaybeUninit<T>; N],
+    initialized: usize,
+}
+
+impl<T, const N: usize> Guard<'_, T, N> {
+    /// Writes `item` to the first uninitialized element.
+    ///
+    /// # Safety
+    ///
+    /// The array must not be full yet.
+    #[inline]
+    unsafe fn push_unchecked(&mut self, item: T) {
+        // SAFETY: the caller promises that `initialized < N`.
+        unsafe { self.array.get_unchecked_mut(self.initialized).write(item) };
+        self.initialized += 1;
+    }
+}
+
+impl<T, const N: usize> Drop for Guard<'_, T, N> {
+    fn drop(&mut self) {
+        let initialized = &mut self.array[..self.initialized];
+        // SAFETY: the first `initialized` elements have been written to, and
+        // nothing else owns them yet.
+        unsafe { ptr::drop_in_place(MaybeUninit::slice_get_mut(initialized)) };
+    }
+}
+
 /// Utility trait implemented only on arrays of fixed size
 ///
 /// This trait can be used to implement other traits on fixed-size arrays
@@ -91,6 +217,61 @@ impl From<Infallible> for TryFromSliceError {
     }
 }
 
+#[lang = "array"]
+impl<T, const N: usize> [T; N] {
+    /// Returns an array of the same size as `self`, with `f` applied to each
+    /// element in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_map)]
+    /// let x = [1, 2, 3];
+    /// let y = x.map(|v| v + 1);
+    /// assert_eq!(y, [2, 3, 4]);
+    ///
+    /// let x = [1, 2, 3];
+    /// let mut temp = 0;
+    /// let y = x.map(|v| { temp += 1; v * temp });
+    /// assert_eq!(y, [1, 4, 9]);
+    ///
+    /// let x = ["Ferris", "Bueller's", "Day", "Off"];
+    /// let y = x.map(|v| v.len());
+    /// assert_eq!(y, [6, 9, 3, 3]);
+    /// ```
+    #[unstable(feature = "array_map", issue = "none")]
+    pub fn map<F, U>(self, f: F) -> [U; N]
+    where
+        F: FnMut(T) -> U,
+    {
+        // SAFETY: the iterator yields exactly `N` items.
+        unsafe { collect_into_array_unchecked(&mut IntoIter::new(self).map(f)) }
+    }
+
+    /// "Zips up" two arrays into a single array of pairs.
+    ///
+    /// `zip()` returns a new array where every element is a tuple where the
+    /// first element comes from the first array, and the second element comes
+    /// from the second array. In other words, it zips two arrays together,
+    /// into a single one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_zip)]
+    /// let x = [1, 2, 3];
+    /// let y = [4, 5, 6];
+    /// let z = x.zip(y);
+    /// assert_eq!(z, [(1, 4), (2, 5), (3, 6)]);
+    /// ```
+    #[unstable(feature = "array_zip", issue = "none")]
+    pub fn zip<U>(self, rhs: [U; N]) -> [(T, U); N] {
+        let mut iter = IntoIter::new(self).zip(IntoIter::new(rhs));
+        // SAFETY: both iterators yield exactly `N` items.
+        unsafe { collect_into_array_unchecked(&mut iter) }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, const N: usize> AsRef<[T]> for [T; N] {
     #[inline]
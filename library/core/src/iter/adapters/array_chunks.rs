@@ -0,0 +1,98 @@
+use crate::array;
+use crate::iter::{ExactSizeIterator, Fuse, FusedIterator, Iterator};
+
+/// An iterator over `N` elements of the iterator at a time.
+///
+/// The chunks do not overlap. If `N` does not divide the length of the
+/// iterator, then the last up to `N-1` elements are not yielded, and can be
+/// retrieved with [`into_remainder`].
+///
+/// The underlying iterator is fused: once it returns `None`, it is not
+/// polled again.
+///
+/// This `struct` is created by the [`array_chunks`] method on [`Iterator`]. See
+/// its documentation for more.
+///
+/// [`into_remainder`]: struct.ArrayChunks.html#method.into_remainder
+/// [`array_chunks`]: trait.Iterator.html#method.array_chunks
+/// [`Iterator`]: trait.Iterator.html
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: Fuse<I>,
+    remainder: Option<array::IntoIter<I::Item, N>>,
+}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    pub(in crate::iter) fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        ArrayChunks { iter: iter.fuse(), remainder: None }
+    }
+
+    /// Returns an iterator over the elements of the original iterator that
+    /// were left over after the last chunk, and are not going to be yielded
+    /// by this iterator. It yields at most `N-1` elements.
+    ///
+    /// Returns `None` if the underlying iterator has not run out yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_array_chunks)]
+    ///
+    /// let mut iter = [1, 2, 3, 4, 5].iter().copied().array_chunks::<2>();
+    /// assert_eq!(iter.by_ref().count(), 2);
+    /// assert_eq!(iter.into_remainder().unwrap().collect::<Vec<_>>(), [5]);
+    /// ```
+    #[unstable(feature = "iter_array_chunks", issue = "none")]
+    #[inline]
+    pub fn into_remainder(self) -> Option<array::IntoIter<I::Item, N>> {
+        self.remainder
+    }
+}
+
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+impl<I, const N: usize> Iterator for ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next_chunk() {
+            Ok(chunk) => Some(chunk),
+            Err(remainder) => {
+                // The underlying iterator is fused, so calling `next` again
+                // after it ran out yields an empty remainder, which must not
+                // replace the real one.
+                self.remainder.get_or_insert(remainder);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|n| n / N))
+    }
+}
+
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+impl<I, const N: usize> FusedIterator for ArrayChunks<I, N> where I: Iterator {}
+
+#[unstable(feature = "iter_array_chunks", issue = "none")]
+impl<I, const N: usize> ExactSizeIterator for ArrayChunks<I, N>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len() / N
+    }
+}
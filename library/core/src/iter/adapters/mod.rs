@@ -6,11 +6,13 @@ use crate::ops::{Add, AddAssign, Try};
 use super::{from_fn, LoopState};
 use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator, TrustedLen};
 
+mod array_chunks;
 mod chain;
 mod flatten;
 mod fuse;
 mod zip;
 
+pub use self::array_chunks::ArrayChunks;
 pub use self::chain::Chain;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::flatten::{FlatMap, Flatten};
@@ -2,11 +2,12 @@
 // This file almost exclusively consists of the definition of `Iterator`. We
 // can't split that into multiple files.
 
+use crate::array;
 use crate::cmp::{self, Ordering};
 use crate::ops::{Add, Try};
 
 use super::super::LoopState;
-use super::super::{Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, Fuse};
+use super::super::{ArrayChunks, Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, Fuse};
 use super::super::{FlatMap, Flatten};
 use super::super::{FromIterator, Product, Sum, Zip};
 use super::super::{
@@ -132,6 +133,48 @@ pub trait Iterator {
     #[stable(feature = "rust1", since = "1.0.0")]
     fn next(&mut self) -> Option<Self::Item>;
 
+    /// Advances the iterator and returns an array containing the next `N`
+    /// values.
+    ///
+    /// If there are not enough elements to fill the array then `Err` is
+    /// returned, containing an iterator over the remaining elements.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_next_chunk)]
+    ///
+    /// let mut iter = "lorem".chars();
+    ///
+    /// assert_eq!(iter.next_chunk().unwrap(), ['l', 'o']); // N is inferred as 2
+    /// assert_eq!(iter.next_chunk().unwrap(), ['r', 'e', 'm']); // N is inferred as 3
+    /// assert_eq!(iter.next_chunk::<4>().unwrap_err().count(), 0); // N is explicitly 4
+    /// ```
+    ///
+    /// Splitting a string and getting the first three items:
+    ///
+    /// ```
+    /// #![feature(iter_next_chunk)]
+    ///
+    /// let quote = "not all those who wander are lost";
+    /// let [first, second, third] = quote.split_whitespace().next_chunk::<3>().unwrap();
+    /// assert_eq!(first, "not");
+    /// assert_eq!(second, "all");
+    /// assert_eq!(third, "those");
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_next_chunk", issue = "none")]
+    fn next_chunk<const N: usize>(
+        &mut self,
+    ) -> Result<[Self::Item; N], array::IntoIter<Self::Item, N>>
+    where
+        Self: Sized,
+    {
+        array::iter_next_chunk(self)
+    }
+
     /// Returns the bounds on the remaining length of the iterator.
     ///
     /// Specifically, `size_hint()` returns a tuple where the first element
@@ -387,6 +430,52 @@ pub trait Iterator {
         StepBy::new(self, step)
     }
 
+    /// Returns an iterator over `N` elements of the iterator at a time.
+    ///
+    /// The chunks do not overlap. If `N` does not divide the length of the
+    /// iterator, then the last up to `N-1` elements are not yielded, and can
+    /// be retrieved from the [`into_remainder`] method of the returned
+    /// iterator.
+    ///
+    /// [`into_remainder`]: ArrayChunks::into_remainder
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_array_chunks)]
+    ///
+    /// let mut iter = "lorem".chars().array_chunks();
+    /// assert_eq!(iter.next(), Some(['l', 'o']));
+    /// assert_eq!(iter.next(), Some(['r', 'e']));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_remainder().unwrap().collect::<Vec<_>>(), ['m']);
+    /// ```
+    ///
+    /// Decoding little-endian integers from a byte stream:
+    ///
+    /// ```
+    /// #![feature(iter_array_chunks)]
+    ///
+    /// let bytes = [1u8, 0, 2, 0, 3, 1];
+    /// let words: Vec<u16> =
+    ///     bytes.iter().copied().array_chunks().map(u16::from_le_bytes).collect();
+    /// assert_eq!(words, [1, 2, 259]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_array_chunks", issue = "none")]
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayChunks::new(self)
+    }
+
     /// Takes two iterators and creates a new iterator over both in sequence.
     ///
     /// `chain()` will return a new iterator which will first iterate over
@@ -277,6 +277,11 @@ impl<T> OnceCell<T> {
 
 /// A value which is initialized on the first access.
 ///
+/// If the initializing function panics, the panic is propagated to the caller
+/// and the `Lazy` is poisoned: the function has been consumed, so every later
+/// access panics as well. Accessing the value again from inside the
+/// initializing function also panics.
+///
 /// # Examples
 ///
 /// ```
@@ -247,6 +247,8 @@ pub mod panicking;
 pub mod pin;
 pub mod raw;
 pub mod result;
+#[unstable(feature = "portable_simd", issue = "none")]
+pub mod simd;
 pub mod sync;
 
 #[cfg(not(test))] // See #65860
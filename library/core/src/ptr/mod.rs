@@ -51,6 +51,21 @@
 //! has size 0, i.e., even if memory is not actually touched. Consider using
 //! [`NonNull::dangling`] in such cases.
 //!
+//! ## Provenance
+//!
+//! A pointer is more than its address: it also carries the *provenance* of
+//! the allocation it was derived from, which determines the memory it may
+//! access. Casting a pointer to an integer and back loses track of that,
+//! which makes such code harder to check and which some targets (like CHERI)
+//! do not support at all.
+//!
+//! Code that only needs to inspect or change the address of a pointer, for
+//! example to store tags in its low bits, can instead use [`addr`],
+//! [`with_addr`] and [`map_addr`], which keep the provenance of the original
+//! pointer. Pointers that are never dereferenced, like sentinel values, can
+//! be made with [`invalid`]. Code that really needs integer-to-pointer casts
+//! should make them explicit with [`expose_addr`] and [`from_exposed_addr`].
+//!
 //! [aliasing]: ../../nomicon/aliasing.html
 //! [book]: ../../book/ch19-01-unsafe-rust.html#dereferencing-a-raw-pointer
 //! [ub]: ../../reference/behavior-considered-undefined.html
@@ -64,6 +79,12 @@
 //! [`read_volatile`]: ./fn.read_volatile.html
 //! [`write_volatile`]: ./fn.write_volatile.html
 //! [`NonNull::dangling`]: ./struct.NonNull.html#method.dangling
+//! [`addr`]: ../../std/primitive.pointer.html#method.addr
+//! [`with_addr`]: ../../std/primitive.pointer.html#method.with_addr
+//! [`map_addr`]: ../../std/primitive.pointer.html#method.map_addr
+//! [`expose_addr`]: ../../std/primitive.pointer.html#method.expose_addr
+//! [`invalid`]: ./fn.invalid.html
+//! [`from_exposed_addr`]: ./fn.from_exposed_addr.html
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
@@ -225,6 +246,93 @@ pub const fn null_mut<T>() -> *mut T {
     0 as *mut T
 }
 
+/// Creates a pointer with the given address and no provenance.
+///
+/// The pointer is not valid for any access of non-zero size, as it is not
+/// derived from any allocation. This is useful for sentinel values and for
+/// dangling, but aligned, pointers to use for zero-sized accesses.
+///
+/// Unlike `addr as *const T`, this makes clear that the pointer is not meant to
+/// point into memory, so it is accepted under strict provenance.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let p: *const u64 = ptr::invalid(8);
+/// assert_eq!(p.addr(), 8);
+/// assert!(!p.is_null());
+/// ```
+#[inline(always)]
+#[unstable(feature = "strict_provenance", issue = "none")]
+pub const fn invalid<T>(addr: usize) -> *const T {
+    // FIXME: this should be a provenance-free cast once the compiler has one
+    addr as *const T
+}
+
+/// Creates a mutable pointer with the given address and no provenance.
+///
+/// See [`invalid`] for details.
+///
+/// [`invalid`]: ./fn.invalid.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let p: *mut u64 = ptr::invalid_mut(8);
+/// assert_eq!(p.addr(), 8);
+/// ```
+#[inline(always)]
+#[unstable(feature = "strict_provenance", issue = "none")]
+pub const fn invalid_mut<T>(addr: usize) -> *mut T {
+    addr as *mut T
+}
+
+/// Converts an address back to a pointer, picking up a previously exposed
+/// provenance.
+///
+/// This is an explicit integer-to-pointer cast. The resulting pointer may only
+/// be used to access memory whose allocation has had its address exposed with
+/// [`expose_addr`] before. Prefer [`with_addr`] where possible: integer-to-pointer
+/// casts are not supported on all targets.
+///
+/// [`expose_addr`]: ../../std/primitive.pointer.html#method.expose_addr
+/// [`with_addr`]: ../../std/primitive.pointer.html#method.with_addr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let x = 5;
+/// let addr = (&x as *const i32).expose_addr();
+/// let p: *const i32 = ptr::from_exposed_addr(addr);
+/// assert_eq!(unsafe { *p }, 5);
+/// ```
+#[inline(always)]
+#[unstable(feature = "strict_provenance", issue = "none")]
+pub fn from_exposed_addr<T>(addr: usize) -> *const T {
+    addr as *const T
+}
+
+/// Converts an address back to a mutable pointer, picking up a previously
+/// exposed provenance.
+///
+/// See [`from_exposed_addr`] for details.
+///
+/// [`from_exposed_addr`]: ./fn.from_exposed_addr.html
+#[inline(always)]
+#[unstable(feature = "strict_provenance", issue = "none")]
+pub fn from_exposed_addr_mut<T>(addr: usize) -> *mut T {
+    addr as *mut T
+}
+
 #[repr(C)]
 pub(crate) union Repr<T> {
     pub(crate) rust: *const [T],
@@ -37,6 +37,115 @@ impl<T: ?Sized> *mut T {
         self as _
     }
 
+    /// Gets the address of the pointer, without its provenance.
+    ///
+    /// This is meant to be like `self as usize`, except that it doesn't
+    /// expose the provenance of the pointer, so that the address cannot be
+    /// turned back into a pointer that is valid for any access. Use
+    /// [`with_addr`] to put an address back into a pointer.
+    ///
+    /// However, this is currently implemented as `self as usize`, so it *does*
+    /// expose the provenance, and is the same as [`expose_addr`]. Code must not
+    /// rely on either behavior.
+    ///
+    /// For wide pointers, this is the address of the data part.
+    ///
+    /// [`with_addr`]: #method.with_addr
+    /// [`expose_addr`]: #method.expose_addr
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn addr(self) -> usize {
+        // FIXME: this should not expose the provenance once the compiler can tell the two apart
+        self as *mut () as usize
+    }
+
+    /// Gets the address of the pointer and exposes its provenance.
+    ///
+    /// This is the same as `self as usize`. The address can be turned back into
+    /// a pointer with [`from_exposed_addr_mut`], which is not
+    /// possible with [`addr`].
+    ///
+    /// [`addr`]: #method.addr
+    /// [`from_exposed_addr_mut`]: ../../std/ptr/fn.from_exposed_addr_mut.html
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn expose_addr(self) -> usize {
+        self as *mut () as usize
+    }
+
+    /// Creates a pointer with the given address and the provenance of `self`.
+    ///
+    /// This is equivalent to offsetting `self` with `wrapping_offset` to the
+    /// new address, so the result is only valid to access memory of the same
+    /// allocation as `self`.
+    ///
+    /// # Examples
+    ///
+    /// Storing a flag in the low bit of an aligned pointer:
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = [1u16, 2, 3];
+    /// let p = x.as_mut_ptr();
+    /// let tagged = p.map_addr(|addr| addr | 1);
+    /// assert_eq!(tagged.addr() & 1, 1);
+    ///
+    /// let untagged = tagged.with_addr(tagged.addr() & !1);
+    /// assert_eq!(unsafe { *untagged }, 1);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Self
+    where
+        T: Sized,
+    {
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        self.cast::<u8>().wrapping_offset(offset).cast::<T>()
+    }
+
+    /// Creates a pointer with an address computed from the address of `self`,
+    /// keeping the provenance of `self`.
+    ///
+    /// This is `self.with_addr(f(self.addr()))`, for tagging and untagging
+    /// pointers. See [`with_addr`] for an example.
+    ///
+    /// [`with_addr`]: #method.with_addr
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn map_addr(self, f: impl FnOnce(usize) -> usize) -> Self
+    where
+        T: Sized,
+    {
+        self.with_addr(f(self.addr()))
+    }
+
+    /// Returns whether the pointer is aligned to `align`.
+    ///
+    /// This only looks at the address of the pointer: it is aligned if the
+    /// address is a multiple of `align`.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if `align` is not a power-of-two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = [1u16, 2, 3];
+    /// let p = x.as_mut_ptr();
+    /// assert!(p.is_aligned_to(2));
+    /// assert!(!p.cast::<u8>().wrapping_add(1).is_aligned_to(2));
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn is_aligned_to(self, align: usize) -> bool {
+        assert!(align.is_power_of_two(), "is_aligned_to: align is not a power-of-two");
+        self.addr() & (align - 1) == 0
+    }
+
     /// Returns `None` if the pointer is null, or else returns a reference to
     /// the value wrapped in `Some`.
     ///
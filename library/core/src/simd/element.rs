@@ -0,0 +1,199 @@
+//! Element types and lane counts supported by `Simd` and `Mask`.
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Marker trait for types that may be used as the lanes of a SIMD vector.
+///
+/// This trait is sealed: it is implemented for the primitive integer types
+/// other than `i128` and `u128`, and for `f32` and `f64`.
+///
+/// # Safety
+///
+/// The type must be a primitive the compiler can place in a vector register.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait SimdElement: Sealed + Copy {
+    /// The type of the lanes of a mask for vectors of this element type.
+    ///
+    /// This is the signed integer type of the same width, with `-1` meaning
+    /// `true` and `0` meaning `false`.
+    type Mask: MaskElement;
+}
+
+/// Marker trait for the signed integer types used as the lanes of a mask.
+///
+/// This trait is sealed and implemented for `i8`, `i16`, `i32`, `i64` and
+/// `isize`.
+///
+/// # Safety
+///
+/// `TRUE` must have every bit set and `FALSE` must have every bit cleared.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait MaskElement: SimdElement<Mask = Self> + PartialEq {
+    #[doc(hidden)]
+    const TRUE: Self;
+    #[doc(hidden)]
+    const FALSE: Self;
+}
+
+/// Implemented by every [`SimdElement`] for the lane counts a vector of that
+/// element type may have: 2, 4, 8, 16, 32 and 64.
+///
+/// Lane counts are limited to powers of two so that vectors map cleanly onto
+/// the registers of the target, splitting into several registers if a vector
+/// is too wide for a single one.
+///
+/// # Safety
+///
+/// The representation types must be `#[repr(simd)]` types with `LANES` lanes
+/// of `Self` and of `Self::Mask` respectively, and pointer vectors likewise.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait SupportedLanes<const LANES: usize>: SimdElement {
+    #[doc(hidden)]
+    type Repr: Copy;
+    #[doc(hidden)]
+    type MaskRepr: Copy;
+    #[doc(hidden)]
+    type PtrRepr: Copy;
+
+    #[doc(hidden)]
+    fn splat(value: Self) -> Self::Repr;
+    #[doc(hidden)]
+    fn splat_mask(value: Self::Mask) -> Self::MaskRepr;
+    #[doc(hidden)]
+    fn shuffle<S: super::Shuffle<LANES>>(a: Self::Repr, b: Self::Repr) -> Self::Repr;
+}
+
+macro_rules! impl_element {
+    ($($ty:ty => $mask:ty),*) => {
+        $(
+            impl Sealed for $ty {}
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl SimdElement for $ty {
+                type Mask = $mask;
+            }
+        )*
+    }
+}
+
+impl_element! {
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, isize => isize,
+    u8 => i8, u16 => i16, u32 => i32, u64 => i64, usize => isize,
+    f32 => i32, f64 => i64
+}
+
+macro_rules! impl_mask_element {
+    ($($ty:ty),*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl MaskElement for $ty {
+                const TRUE: Self = -1;
+                const FALSE: Self = 0;
+            }
+        )*
+    }
+}
+
+impl_mask_element! { i8, i16, i32, i64, isize }
+
+// The `#[repr(simd)]` types that vectors are lowered to. These have to be
+// tuple structs with one field per lane, so the lane count is spelled out.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Repr2<T>(T, T);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Repr4<T>(T, T, T, T);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Repr8<T>(T, T, T, T, T, T, T, T);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Repr16<T>(T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Repr32<T>(
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Repr64<T>(
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+);
+
+macro_rules! impl_supported_lanes {
+    ($($lanes:literal => $repr:ident, $shuffle:ident, |$x:ident| $splat:expr;)*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl<T: SimdElement> SupportedLanes<$lanes> for T {
+                type Repr = $repr<T>;
+                type MaskRepr = $repr<T::Mask>;
+                type PtrRepr = $repr<*const T>;
+
+                #[inline]
+                fn splat($x: T) -> $repr<T> {
+                    $splat
+                }
+
+                #[inline]
+                fn splat_mask($x: T::Mask) -> $repr<T::Mask> {
+                    $splat
+                }
+
+                #[inline]
+                fn shuffle<S: super::Shuffle<$lanes>>(a: $repr<T>, b: $repr<T>) -> $repr<T> {
+                    // SAFETY: out of bounds indices are rejected when the
+                    // shuffle is monomorphized, so this is always sound.
+                    unsafe { super::intrinsics::$shuffle(a, b, S::INDEX) }
+                }
+            }
+        )*
+    }
+}
+
+impl_supported_lanes! {
+    2 => Repr2, simd_shuffle2, |x| Repr2(x, x);
+    4 => Repr4, simd_shuffle4, |x| Repr4(x, x, x, x);
+    8 => Repr8, simd_shuffle8, |x| Repr8(x, x, x, x, x, x, x, x);
+    16 => Repr16, simd_shuffle16, |x| Repr16(x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x);
+    32 => Repr32, simd_shuffle32, |x| Repr32(
+        x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x,
+        x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x,
+    );
+    64 => Repr64, simd_shuffle64, |x| Repr64(
+        x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x,
+        x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x,
+        x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x,
+        x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x,
+    );
+}
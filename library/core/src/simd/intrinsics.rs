@@ -0,0 +1,68 @@
+//! The platform intrinsics `Simd` and `Mask` are lowered to.
+//!
+//! These are generic over any `#[repr(simd)]` type and are checked and
+//! translated to LLVM vector instructions when monomorphized, see
+//! `librustc_codegen_llvm/intrinsic.rs`. Calling them with types that aren't
+//! SIMD vectors, or with vectors that don't match up, is a compile error
+//! rather than undefined behavior.
+
+extern "platform-intrinsic" {
+    // Lane-wise arithmetic.
+    pub(crate) fn simd_add<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_sub<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_mul<T>(x: T, y: T) -> T;
+    /// Dividing an integer by zero, or `MIN` by `-1`, is undefined behavior.
+    pub(crate) fn simd_div<T>(x: T, y: T) -> T;
+    /// Dividing an integer by zero, or `MIN` by `-1`, is undefined behavior.
+    pub(crate) fn simd_rem<T>(x: T, y: T) -> T;
+    /// Shifting by the bit width of the element type or more is undefined
+    /// behavior.
+    pub(crate) fn simd_shl<T>(x: T, y: T) -> T;
+    /// Shifting by the bit width of the element type or more is undefined
+    /// behavior.
+    pub(crate) fn simd_shr<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_and<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_or<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_xor<T>(x: T, y: T) -> T;
+
+    // Floating point.
+    pub(crate) fn simd_fabs<T>(x: T) -> T;
+    pub(crate) fn simd_fsqrt<T>(x: T) -> T;
+    pub(crate) fn simd_fmin<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_fmax<T>(x: T, y: T) -> T;
+
+    // Comparisons, returning a vector of signed integers of the same lane
+    // count with every bit of a lane set where the comparison holds.
+    pub(crate) fn simd_eq<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_ne<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_lt<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_le<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_gt<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_ge<T, U>(x: T, y: T) -> U;
+
+    // Reductions.
+    pub(crate) fn simd_reduce_add_ordered<T, U>(x: T, acc: U) -> U;
+    pub(crate) fn simd_reduce_mul_ordered<T, U>(x: T, acc: U) -> U;
+    pub(crate) fn simd_reduce_min<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_max<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_and<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_or<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_xor<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_all<T>(x: T) -> bool;
+    pub(crate) fn simd_reduce_any<T>(x: T) -> bool;
+
+    // Shuffles, where `idx` has to be a constant.
+    pub(crate) fn simd_shuffle2<T, U>(x: T, y: T, idx: [u32; 2]) -> U;
+    pub(crate) fn simd_shuffle4<T, U>(x: T, y: T, idx: [u32; 4]) -> U;
+    pub(crate) fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
+    pub(crate) fn simd_shuffle16<T, U>(x: T, y: T, idx: [u32; 16]) -> U;
+    pub(crate) fn simd_shuffle32<T, U>(x: T, y: T, idx: [u32; 32]) -> U;
+    pub(crate) fn simd_shuffle64<T, U>(x: T, y: T, idx: [u32; 64]) -> U;
+
+    /// Picks the lanes of `t` where `m` is set and those of `f` elsewhere.
+    pub(crate) fn simd_select<M, T>(m: M, t: T, f: T) -> T;
+
+    /// Loads the lanes where `m` is set from the pointers in `ptr`, taking the
+    /// lanes of `val` elsewhere. Only the pointers of enabled lanes are read.
+    pub(crate) fn simd_gather<T, P, M>(val: T, ptr: P, m: M) -> T;
+}
@@ -0,0 +1,251 @@
+use super::intrinsics;
+use super::{MaskElement, Simd, SupportedLanes};
+use crate::fmt;
+use crate::mem::MaybeUninit;
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+use crate::ptr;
+
+/// A SIMD vector mask of `LANES` lanes, for vectors of `T`.
+///
+/// Masks are returned by the comparison methods of [`Simd`], such as
+/// [`lanes_lt`], and are used to select lanes from vectors. Each lane is
+/// stored as a signed integer of the same width as `T` with all of its bits
+/// either set or cleared, which is what the targets' vector comparison
+/// instructions produce.
+///
+/// [`Simd`]: struct.Simd.html
+/// [`lanes_lt`]: struct.Simd.html#method.lanes_lt
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::Simd;
+///
+/// let a = Simd::from_array([1, 5, 3, 7]);
+/// let b = Simd::from_array([4, 2, 6, 0]);
+/// let mask = a.lanes_lt(b);
+/// assert_eq!(mask.to_array(), [true, false, true, false]);
+/// assert_eq!(mask.select(a, b).to_array(), [1, 2, 3, 0]);
+/// ```
+#[repr(transparent)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Mask<T, const LANES: usize>(pub(super) T::MaskRepr)
+where
+    T: SupportedLanes<LANES>;
+
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    /// Constructs a mask with every lane set to `value`.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn splat(value: bool) -> Self {
+        Mask(T::splat_mask(mask_lane(value)))
+    }
+
+    /// Converts an array of booleans to a mask.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn from_array(array: [bool; LANES]) -> Self {
+        let mut lanes = MaybeUninit::<[T::Mask; LANES]>::uninit();
+        for (i, &value) in array.iter().enumerate() {
+            // SAFETY: `i` is in bounds of the array of lanes.
+            unsafe { (lanes.as_mut_ptr() as *mut T::Mask).add(i).write(mask_lane(value)) };
+        }
+        // SAFETY: every lane has been initialized, and the representation has
+        // the same size as the array.
+        Mask(unsafe { ptr::read_unaligned(lanes.as_ptr() as *const T::MaskRepr) })
+    }
+
+    /// Converts the mask to an array of booleans.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn to_array(self) -> [bool; LANES] {
+        // SAFETY: the mask has the same size as the array of lanes, and at
+        // least its alignment.
+        let lanes = unsafe { &*(&self.0 as *const T::MaskRepr as *const [T::Mask; LANES]) };
+        let mut array = [false; LANES];
+        for (value, lane) in array.iter_mut().zip(lanes) {
+            *value = *lane == <T::Mask as MaskElement>::TRUE;
+        }
+        array
+    }
+
+    /// Tests the value of the lane at index `lane`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is out of bounds.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn test(&self, lane: usize) -> bool {
+        self.to_array()[lane]
+    }
+
+    /// Sets the value of the lane at index `lane`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is out of bounds.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn set(&mut self, lane: usize, value: bool) {
+        let mut array = self.to_array();
+        array[lane] = value;
+        *self = Self::from_array(array);
+    }
+
+    /// Returns `true` if any lane is set.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn any(self) -> bool {
+        // SAFETY: the mask is a vector of integers.
+        unsafe { intrinsics::simd_reduce_any(self.0) }
+    }
+
+    /// Returns `true` if all lanes are set.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn all(self) -> bool {
+        // SAFETY: the mask is a vector of integers.
+        unsafe { intrinsics::simd_reduce_all(self.0) }
+    }
+
+    /// Picks the lanes of `true_values` where the mask is set and the lanes
+    /// of `false_values` where it isn't.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn select(
+        self,
+        true_values: Simd<T, LANES>,
+        false_values: Simd<T, LANES>,
+    ) -> Simd<T, LANES> {
+        // SAFETY: the mask is a vector of integers with the lane count of the
+        // vectors.
+        Simd(unsafe { intrinsics::simd_select(self.0, true_values.0, false_values.0) })
+    }
+}
+
+fn mask_lane<M: MaskElement>(value: bool) -> M {
+    if value { M::TRUE } else { M::FALSE }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Copy for Mask<T, LANES> where T: SupportedLanes<LANES> {}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Clone for Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Default for Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(false)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialEq for Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        !(*self ^ *other).any()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> fmt::Debug for Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_array(), f)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<[bool; LANES]> for Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn from(array: [bool; LANES]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Mask<T, LANES>> for [bool; LANES]
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn from(mask: Mask<T, LANES>) -> Self {
+        mask.to_array()
+    }
+}
+
+macro_rules! bitwise_ops {
+    ($($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident => $intrinsic:ident;)*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $trait for Mask<T, LANES>
+            where
+                T: SupportedLanes<LANES>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self {
+                    // SAFETY: masks are vectors of integers.
+                    Mask(unsafe { intrinsics::$intrinsic(self.0, rhs.0) })
+                }
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $assign_trait for Mask<T, LANES>
+            where
+                T: SupportedLanes<LANES>,
+            {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = $trait::$fn(*self, rhs);
+                }
+            }
+        )*
+    }
+}
+
+bitwise_ops! {
+    BitAnd, bitand, BitAndAssign, bitand_assign => simd_and;
+    BitOr, bitor, BitOrAssign, bitor_assign => simd_or;
+    BitXor, bitxor, BitXorAssign, bitxor_assign => simd_xor;
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Not for Mask<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self ^ Self::splat(true)
+    }
+}
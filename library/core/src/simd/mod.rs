@@ -0,0 +1,60 @@
+//! Portable SIMD vector types.
+//!
+//! The [`Simd`] type holds a fixed number of lanes of a primitive type and
+//! applies operations to all of them at once. Unlike the intrinsics in
+//! [`arch`], which are specific to one instruction set and need
+//! `#[target_feature]` or `cfg` checks to be used, code written with `Simd` is
+//! the same on every target: it is compiled to the vector instructions the
+//! target has, and falls back to operating on the lanes one by one where it
+//! has none.
+//!
+//! Vectors support lane-wise arithmetic through the operator traits,
+//! comparisons returning a [`Mask`], reductions such as
+//! [`horizontal_sum`], rearranging lanes with constant [`Shuffle`] patterns,
+//! and loading lanes from arbitrary indices of a slice with [`gather_or`].
+//!
+//! [`arch`]: ../arch/index.html
+//! [`Simd`]: struct.Simd.html
+//! [`Mask`]: struct.Mask.html
+//! [`Shuffle`]: trait.Shuffle.html
+//! [`horizontal_sum`]: struct.Simd.html#method.horizontal_sum
+//! [`gather_or`]: struct.Simd.html#method.gather_or
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//! use core::simd::Simd;
+//!
+//! fn dot(a: &[f32], b: &[f32]) -> f32 {
+//!     assert_eq!(a.len(), b.len());
+//!     let mut sums = Simd::<f32, 8>::splat(0.0);
+//!     for (a, b) in a.chunks_exact(8).zip(b.chunks_exact(8)) {
+//!         sums += Simd::from_slice(a) * Simd::from_slice(b);
+//!     }
+//!     let tail = a.len() - a.len() % 8;
+//!     let rest: f32 = a[tail..].iter().zip(&b[tail..]).map(|(a, b)| a * b).sum();
+//!     sums.horizontal_sum() + rest
+//! }
+//!
+//! let a: Vec<f32> = (0..20).map(|i| i as f32).collect();
+//! let b = vec![2.0; 20];
+//! assert_eq!(dot(&a, &b), 380.0);
+//! ```
+
+mod element;
+mod intrinsics;
+mod masks;
+mod ops;
+mod reduction;
+mod swizzle;
+mod vector;
+
+#[unstable(feature = "portable_simd", issue = "none")]
+pub use element::{MaskElement, SimdElement, SupportedLanes};
+#[unstable(feature = "portable_simd", issue = "none")]
+pub use masks::Mask;
+#[unstable(feature = "portable_simd", issue = "none")]
+pub use swizzle::Shuffle;
+#[unstable(feature = "portable_simd", issue = "none")]
+pub use vector::Simd;
@@ -0,0 +1,150 @@
+//! Lane-wise operators for `Simd`.
+//!
+//! Integer arithmetic wraps on overflow, like it does for the primitive types
+//! in release builds, since checking every lane for overflow would defeat the
+//! purpose of using vectors. Division, remainder and shifts still panic where
+//! the corresponding operation on the primitive types always panics, because
+//! the instructions they are lowered to have undefined results in those cases.
+
+use super::intrinsics;
+use super::{Simd, SupportedLanes};
+use crate::mem;
+use crate::ops::{Add, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+use crate::ops::{AddAssign, DivAssign, MulAssign, RemAssign, ShlAssign, ShrAssign, SubAssign};
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+macro_rules! binary_op {
+    (
+        $ty:ty, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident,
+        |$lhs:ident, $rhs:ident| $check:block => $intrinsic:ident
+    ) => {
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<const LANES: usize> $trait for Simd<$ty, LANES>
+        where
+            $ty: SupportedLanes<LANES>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                let ($lhs, $rhs) = (self, rhs);
+                $check
+                // SAFETY: the operands are vectors of the same primitive
+                // type, and the check above ruled out undefined results.
+                Simd(unsafe { intrinsics::$intrinsic($lhs.0, $rhs.0) })
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<const LANES: usize> $assign_trait for Simd<$ty, LANES>
+        where
+            $ty: SupportedLanes<LANES>,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! common_ops {
+    ($($ty:ty),*) => {
+        $(
+            binary_op!($ty, Add, add, AddAssign, add_assign, |_a, _b| {} => simd_add);
+            binary_op!($ty, Sub, sub, SubAssign, sub_assign, |_a, _b| {} => simd_sub);
+            binary_op!($ty, Mul, mul, MulAssign, mul_assign, |_a, _b| {} => simd_mul);
+        )*
+    }
+}
+
+macro_rules! int_ops {
+    ($($ty:ty),*) => {
+        $(
+            binary_op!($ty, Div, div, DivAssign, div_assign, |a, b| {
+                if b.lanes_eq(Simd::splat(0)).any() {
+                    panic!("attempt to divide by zero");
+                }
+                #[allow(unused_comparisons)]
+                let signed = <$ty>::MIN < 0;
+                let overflow = a.lanes_eq(Simd::splat(<$ty>::MIN)) & b.lanes_eq(Simd::splat(!0));
+                if signed && overflow.any() {
+                    panic!("attempt to divide with overflow");
+                }
+            } => simd_div);
+            binary_op!($ty, Rem, rem, RemAssign, rem_assign, |a, b| {
+                if b.lanes_eq(Simd::splat(0)).any() {
+                    panic!("attempt to calculate the remainder with a divisor of zero");
+                }
+                #[allow(unused_comparisons)]
+                let signed = <$ty>::MIN < 0;
+                let overflow = a.lanes_eq(Simd::splat(<$ty>::MIN)) & b.lanes_eq(Simd::splat(!0));
+                if signed && overflow.any() {
+                    panic!("attempt to calculate the remainder with overflow");
+                }
+            } => simd_rem);
+            binary_op!($ty, Shl, shl, ShlAssign, shl_assign, |_a, b| {
+                let bits = Simd::splat((mem::size_of::<$ty>() * 8) as $ty);
+                if (b.lanes_ge(bits) | b.lanes_lt(Simd::splat(0))).any() {
+                    panic!("attempt to shift left with overflow");
+                }
+            } => simd_shl);
+            binary_op!($ty, Shr, shr, ShrAssign, shr_assign, |_a, b| {
+                let bits = Simd::splat((mem::size_of::<$ty>() * 8) as $ty);
+                if (b.lanes_ge(bits) | b.lanes_lt(Simd::splat(0))).any() {
+                    panic!("attempt to shift right with overflow");
+                }
+            } => simd_shr);
+            binary_op!($ty, BitAnd, bitand, BitAndAssign, bitand_assign, |_a, _b| {} => simd_and);
+            binary_op!($ty, BitOr, bitor, BitOrAssign, bitor_assign, |_a, _b| {} => simd_or);
+            binary_op!($ty, BitXor, bitxor, BitXorAssign, bitxor_assign, |_a, _b| {} => simd_xor);
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<const LANES: usize> Not for Simd<$ty, LANES>
+            where
+                $ty: SupportedLanes<LANES>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self {
+                    self ^ Simd::splat(!0)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! neg_op {
+    ($($ty:ty => $zero:expr),*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<const LANES: usize> Neg for Simd<$ty, LANES>
+            where
+                $ty: SupportedLanes<LANES>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    Simd::splat($zero) - self
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! float_ops {
+    ($($ty:ty),*) => {
+        $(
+            binary_op!($ty, Div, div, DivAssign, div_assign, |_a, _b| {} => simd_div);
+            binary_op!($ty, Rem, rem, RemAssign, rem_assign, |_a, _b| {} => simd_rem);
+        )*
+    }
+}
+
+common_ops! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64 }
+int_ops! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+float_ops! { f32, f64 }
+// Subtracting from negative zero keeps the sign of zero lanes right.
+neg_op! { i8 => 0, i16 => 0, i32 => 0, i64 => 0, isize => 0, f32 => -0.0, f64 => -0.0 }
@@ -0,0 +1,159 @@
+use super::intrinsics;
+use super::{Simd, SupportedLanes};
+
+macro_rules! int_reductions {
+    ($($ty:ty),*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                $ty: SupportedLanes<LANES>,
+            {
+                /// Returns the sum of the lanes of the vector, wrapping on
+                /// overflow.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_sum(self) -> $ty {
+                    // SAFETY: the accumulator has the element type.
+                    unsafe { intrinsics::simd_reduce_add_ordered(self.0, 0) }
+                }
+
+                /// Returns the product of the lanes of the vector, wrapping on
+                /// overflow.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_product(self) -> $ty {
+                    // SAFETY: the accumulator has the element type.
+                    unsafe { intrinsics::simd_reduce_mul_ordered(self.0, 1) }
+                }
+
+                /// Returns the smallest lane of the vector.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_min(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_min(self.0) }
+                }
+
+                /// Returns the largest lane of the vector.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_max(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_max(self.0) }
+                }
+
+                /// Returns the bitwise "and" of the lanes of the vector.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_and(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_and(self.0) }
+                }
+
+                /// Returns the bitwise "or" of the lanes of the vector.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_or(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_or(self.0) }
+                }
+
+                /// Returns the bitwise "xor" of the lanes of the vector.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_xor(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_xor(self.0) }
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! float_methods {
+    ($($ty:ty),*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                $ty: SupportedLanes<LANES>,
+            {
+                /// Returns the sum of the lanes of the vector.
+                ///
+                /// The lanes are added up in order, so the result is the same
+                /// as summing the lanes of `to_array()` one by one.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_sum(self) -> $ty {
+                    // SAFETY: the accumulator has the element type.
+                    unsafe { intrinsics::simd_reduce_add_ordered(self.0, -0.0) }
+                }
+
+                /// Returns the product of the lanes of the vector.
+                ///
+                /// The lanes are multiplied in order, so the result is the same
+                /// as multiplying the lanes of `to_array()` one by one.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_product(self) -> $ty {
+                    // SAFETY: the accumulator has the element type.
+                    unsafe { intrinsics::simd_reduce_mul_ordered(self.0, 1.0) }
+                }
+
+                /// Returns the smallest lane of the vector, ignoring `NaN`
+                /// lanes unless all lanes are `NaN`.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_min(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_min(self.0) }
+                }
+
+                /// Returns the largest lane of the vector, ignoring `NaN`
+                /// lanes unless all lanes are `NaN`.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn horizontal_max(self) -> $ty {
+                    // SAFETY: the result has the element type.
+                    unsafe { intrinsics::simd_reduce_max(self.0) }
+                }
+
+                /// Computes the absolute value of each lane.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn abs(self) -> Self {
+                    // SAFETY: this is a vector of floats.
+                    Simd(unsafe { intrinsics::simd_fabs(self.0) })
+                }
+
+                /// Computes the square root of each lane.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn sqrt(self) -> Self {
+                    // SAFETY: this is a vector of floats.
+                    Simd(unsafe { intrinsics::simd_fsqrt(self.0) })
+                }
+
+                /// Computes the minimum of each lane and the corresponding
+                /// lane of `other`, like `min` on the primitive type does.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn min(self, other: Self) -> Self {
+                    // SAFETY: these are vectors of floats.
+                    Simd(unsafe { intrinsics::simd_fmin(self.0, other.0) })
+                }
+
+                /// Computes the maximum of each lane and the corresponding
+                /// lane of `other`, like `max` on the primitive type does.
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn max(self, other: Self) -> Self {
+                    // SAFETY: these are vectors of floats.
+                    Simd(unsafe { intrinsics::simd_fmax(self.0, other.0) })
+                }
+            }
+        )*
+    }
+}
+
+int_reductions! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+float_methods! { f32, f64 }
@@ -0,0 +1,49 @@
+use super::{Simd, SupportedLanes};
+
+/// A constant pattern for rearranging the lanes of vectors with
+/// [`Simd::shuffle`].
+///
+/// Index `i` of `INDEX` selects the lane of the output at position `i`:
+/// indices below `LANES` select that lane of the first input, and indices from
+/// `LANES` up to `2 * LANES` select lane `index - LANES` of the second input.
+/// Using an index of `2 * LANES` or more is a compile error.
+///
+/// [`Simd::shuffle`]: struct.Simd.html#method.shuffle
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::{Shuffle, Simd};
+///
+/// struct Interleave;
+///
+/// impl Shuffle<4> for Interleave {
+///     const INDEX: [u32; 4] = [0, 4, 1, 5];
+/// }
+///
+/// let a = Simd::from_array([0, 1, 2, 3]);
+/// let b = Simd::from_array([10, 11, 12, 13]);
+/// assert_eq!(a.shuffle::<Interleave>(b).to_array(), [0, 10, 1, 11]);
+/// ```
+#[unstable(feature = "portable_simd", issue = "none")]
+pub trait Shuffle<const LANES: usize> {
+    /// The index of the input lane for each lane of the output.
+    const INDEX: [u32; LANES];
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    /// Builds a vector from the lanes of `self` and `other`, picking them
+    /// according to the constant pattern `S`.
+    ///
+    /// This is lowered to a single shuffle, which the targets with vector
+    /// instructions can usually do in one or two instructions.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn shuffle<S: Shuffle<LANES>>(self, other: Self) -> Self {
+        Simd(T::shuffle::<S>(self.0, other.0))
+    }
+}
@@ -0,0 +1,309 @@
+use super::intrinsics;
+use super::{Mask, SupportedLanes};
+use crate::fmt;
+use crate::mem::MaybeUninit;
+use crate::ops::{Index, IndexMut};
+use crate::ptr;
+use crate::slice::SliceIndex;
+
+/// A SIMD vector of `LANES` elements of type `T`.
+///
+/// Operations on a `Simd` act on all of its lanes at once and are compiled to
+/// the vector instructions of the target where it has them. Vectors that are
+/// wider than the target's registers are split over several registers, so the
+/// same code can be used regardless of the target's vector width.
+///
+/// `Simd` has the same size as `[T; LANES]`, but may have a larger alignment.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::Simd;
+///
+/// let a = Simd::from_array([1.0f32, 2.0, 3.0, 4.0]);
+/// let b = Simd::splat(10.0);
+/// assert_eq!((a * b).to_array(), [10.0, 20.0, 30.0, 40.0]);
+/// ```
+#[repr(transparent)]
+#[unstable(feature = "portable_simd", issue = "none")]
+pub struct Simd<T, const LANES: usize>(pub(super) T::Repr)
+where
+    T: SupportedLanes<LANES>;
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    /// The number of lanes of this vector.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    pub const LANES: usize = LANES;
+
+    /// Returns the number of lanes of this vector.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn lanes(&self) -> usize {
+        LANES
+    }
+
+    /// Constructs a vector with every lane set to `value`.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn splat(value: T) -> Self {
+        Simd(T::splat(value))
+    }
+
+    /// Converts an array to a vector.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn from_array(array: [T; LANES]) -> Self {
+        // SAFETY: the representation has the same size as the array, and the
+        // read doesn't rely on the array being aligned like the vector.
+        Simd(unsafe { ptr::read_unaligned(&array as *const [T; LANES] as *const T::Repr) })
+    }
+
+    /// Converts the vector to an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn to_array(self) -> [T; LANES] {
+        *self.as_array()
+    }
+
+    /// Returns a reference to the lanes of the vector as an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn as_array(&self) -> &[T; LANES] {
+        // SAFETY: the vector has the same size as the array, and at least
+        // its alignment.
+        unsafe { &*(self as *const Self as *const [T; LANES]) }
+    }
+
+    /// Returns a mutable reference to the lanes of the vector as an array.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn as_mut_array(&mut self) -> &mut [T; LANES] {
+        // SAFETY: see `as_array`.
+        unsafe { &mut *(self as *mut Self as *mut [T; LANES]) }
+    }
+
+    /// Converts the first `LANES` elements of a slice to a vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is shorter than `LANES`.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn from_slice(slice: &[T]) -> Self {
+        assert!(slice.len() >= LANES, "slice is shorter than the vector");
+        // SAFETY: we just checked that the slice has enough elements.
+        Simd(unsafe { ptr::read_unaligned(slice.as_ptr() as *const T::Repr) })
+    }
+
+    /// Reads the lanes selected by `idxs` from `slice`, taking the lane of `or`
+    /// for any index that is out of bounds.
+    ///
+    /// The reads are done with a single gather instruction where the target
+    /// has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::Simd;
+    ///
+    /// let values = [10, 11, 12, 13, 14, 15];
+    /// let idxs = Simd::from_array([9, 3, 0, 5]);
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    ///
+    /// let result = Simd::gather_or(&values, idxs, alt);
+    /// assert_eq!(result.to_array(), [-5, 13, 10, 15]);
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn gather_or(slice: &[T], idxs: Simd<usize, LANES>, or: Self) -> Self
+    where
+        usize: SupportedLanes<LANES>,
+    {
+        Self::gather_select(slice, Mask::splat(true), idxs, or)
+    }
+
+    /// Reads the lanes selected by `idxs` from `slice`, taking the default
+    /// value for any index that is out of bounds.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn gather_or_default(slice: &[T], idxs: Simd<usize, LANES>) -> Self
+    where
+        T: Default,
+        usize: SupportedLanes<LANES>,
+    {
+        Self::gather_or(slice, idxs, Self::splat(T::default()))
+    }
+
+    /// Reads the lanes selected by `idxs` from `slice` where `enable` is set,
+    /// taking the lane of `or` where it isn't or where the index is out of
+    /// bounds.
+    #[unstable(feature = "portable_simd", issue = "none")]
+    #[inline]
+    pub fn gather_select(
+        slice: &[T],
+        enable: Mask<T, LANES>,
+        idxs: Simd<usize, LANES>,
+        or: Self,
+    ) -> Self
+    where
+        usize: SupportedLanes<LANES>,
+    {
+        let idxs = idxs.to_array();
+        let mut enable = enable.to_array();
+        let mut ptrs = MaybeUninit::<[*const T; LANES]>::uninit();
+        for i in 0..LANES {
+            enable[i] &= idxs[i] < slice.len();
+            // SAFETY: `i` is in bounds of the array of pointers. Pointers of
+            // disabled lanes may dangle, but aren't read by the gather.
+            unsafe {
+                let ptr = slice.as_ptr().wrapping_add(idxs[i]);
+                (ptrs.as_mut_ptr() as *mut *const T).add(i).write(ptr);
+            }
+        }
+        let enable = Mask::<T, LANES>::from_array(enable);
+        // SAFETY: all pointers of enabled lanes are in bounds of `slice`, and
+        // the vector of pointers has the same size as the array.
+        unsafe {
+            let ptrs = ptr::read_unaligned(ptrs.as_ptr() as *const T::PtrRepr);
+            Simd(intrinsics::simd_gather(or.0, ptrs, enable.0))
+        }
+    }
+}
+
+macro_rules! comparisons {
+    ($($(#[$attr:meta])* fn $name:ident => $intrinsic:ident;)*) => {
+        impl<T, const LANES: usize> Simd<T, LANES>
+        where
+            T: SupportedLanes<LANES>,
+        {
+            $(
+                $(#[$attr])*
+                #[unstable(feature = "portable_simd", issue = "none")]
+                #[inline]
+                pub fn $name(self, other: Self) -> Mask<T, LANES> {
+                    // SAFETY: comparing two vectors of the same type yields a
+                    // vector of signed integers of the same width.
+                    Mask(unsafe { intrinsics::$intrinsic(self.0, other.0) })
+                }
+            )*
+        }
+    }
+}
+
+comparisons! {
+    /// Tests if each lane is equal to the corresponding lane in `other`.
+    fn lanes_eq => simd_eq;
+    /// Tests if each lane is not equal to the corresponding lane in `other`.
+    fn lanes_ne => simd_ne;
+    /// Tests if each lane is less than the corresponding lane in `other`.
+    fn lanes_lt => simd_lt;
+    /// Tests if each lane is less than or equal to the corresponding lane in
+    /// `other`.
+    fn lanes_le => simd_le;
+    /// Tests if each lane is greater than the corresponding lane in `other`.
+    fn lanes_gt => simd_gt;
+    /// Tests if each lane is greater than or equal to the corresponding lane
+    /// in `other`.
+    fn lanes_ge => simd_ge;
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Copy for Simd<T, LANES> where T: SupportedLanes<LANES> {}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Clone for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Default for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES> + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(T::default())
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialEq for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES> + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // SAFETY: see `lanes_eq`.
+        let mask: Mask<T, LANES> = Mask(unsafe { intrinsics::simd_eq(self.0, other.0) });
+        mask.all()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> fmt::Debug for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_array(), f)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<[T; LANES]> for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn from(array: [T; LANES]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Simd<T, LANES>> for [T; LANES]
+where
+    T: SupportedLanes<LANES>,
+{
+    #[inline]
+    fn from(vector: Simd<T, LANES>) -> Self {
+        vector.to_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, I, const LANES: usize> Index<I> for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_array()[index]
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, I, const LANES: usize> IndexMut<I> for Simd<T, LANES>
+where
+    T: SupportedLanes<LANES>,
+    I: SliceIndex<[T]>,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_array()[index]
+    }
+}
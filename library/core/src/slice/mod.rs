@@ -23,9 +23,10 @@
 // * The `raw` and `bytes` submodules.
 // * Boilerplate trait implementations.
 
+use crate::ascii;
 use crate::cmp;
 use crate::cmp::Ordering::{self, Equal, Greater, Less};
-use crate::fmt;
+use crate::fmt::{self, Write};
 use crate::intrinsics::{assume, exact_div, is_aligned_and_not_null, unchecked_sub};
 use crate::iter::*;
 use crate::marker::{self, Copy, Send, Sized, Sync};
@@ -36,6 +37,7 @@ use crate::option::Option::{None, Some};
 use crate::ptr::{self, NonNull};
 use crate::result::Result;
 use crate::result::Result::{Err, Ok};
+use crate::str::pattern::BytesSearcher;
 
 #[unstable(
     feature = "slice_internals",
@@ -841,6 +843,131 @@ impl<T> [T] {
         ChunksExactMut { v: fst, rem: snd, chunk_size }
     }
 
+    /// Splits the slice into a slice of `N`-element arrays, starting at the beginning of the
+    /// slice, and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let (chunks, remainder) = slice.as_chunks();
+    /// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
+    /// assert_eq!(remainder, &['m']);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "none")]
+    #[inline]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert_ne!(N, 0);
+        let len = self.len() / N;
+        let (multiple_of_n, remainder) = self.split_at(len * N);
+        // SAFETY: `multiple_of_n` holds exactly `len * N` elements, and `[T; N]` is laid out as
+        // `N` consecutive `T`s.
+        let array_slice = unsafe { from_raw_parts(multiple_of_n.as_ptr().cast(), len) };
+        (array_slice, remainder)
+    }
+
+    /// Splits the slice into a slice of `N`-element arrays, starting at the beginning of the
+    /// slice, and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// let (chunks, remainder) = v.as_chunks_mut();
+    /// remainder[0] = 9;
+    /// for chunk in chunks {
+    ///     *chunk = [count; 2];
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 9]);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "none")]
+    #[inline]
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        assert_ne!(N, 0);
+        let len = self.len() / N;
+        let (multiple_of_n, remainder) = self.split_at_mut(len * N);
+        // SAFETY: same as in `as_chunks`, and `multiple_of_n` is borrowed mutably.
+        let array_slice = unsafe { from_raw_parts_mut(multiple_of_n.as_mut_ptr().cast(), len) };
+        (array_slice, remainder)
+    }
+
+    /// Returns an iterator over `N` elements of the slice at a time, starting at the
+    /// beginning of the slice.
+    ///
+    /// The chunks are array references and do not overlap. If `N` does not divide the
+    /// length of the slice, then the last up to `N-1` elements will be omitted and can be
+    /// retrieved from the `remainder` function of the iterator.
+    ///
+    /// This method is the const generic equivalent of [`chunks_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_chunks)]
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.array_chunks();
+    /// assert_eq!(iter.next().unwrap(), &['l', 'o']);
+    /// assert_eq!(iter.next().unwrap(), &['r', 'e']);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &['m']);
+    /// ```
+    ///
+    /// [`chunks_exact`]: #method.chunks_exact
+    #[unstable(feature = "array_chunks", issue = "none")]
+    #[inline]
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, N> {
+        let (array_slice, rem) = self.as_chunks();
+        ArrayChunks { iter: array_slice.iter(), rem }
+    }
+
+    /// Returns an iterator over overlapping windows of `N` elements of the slice, starting
+    /// at the beginning of the slice.
+    ///
+    /// This is the const generic equivalent of [`windows`].
+    ///
+    /// If `N` is greater than the size of the slice, it will return no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_windows)]
+    /// let slice = [0, 1, 2, 3];
+    /// let mut iter = slice.array_windows();
+    /// assert_eq!(iter.next().unwrap(), &[0, 1]);
+    /// assert_eq!(iter.next().unwrap(), &[1, 2]);
+    /// assert_eq!(iter.next().unwrap(), &[2, 3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// [`windows`]: #method.windows
+    #[unstable(feature = "array_windows", issue = "none")]
+    #[inline]
+    pub fn array_windows<const N: usize>(&self) -> ArrayWindows<'_, T, N> {
+        assert_ne!(N, 0);
+        ArrayWindows { v: self }
+    }
+
     /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the end
     /// of the slice.
     ///
@@ -999,6 +1126,98 @@ impl<T> [T] {
         RChunksExactMut { v: snd, rem: fst, chunk_size }
     }
 
+    /// Returns an iterator over the slice producing non-overlapping runs
+    /// of elements using the predicate to separate them.
+    ///
+    /// The predicate is called on two elements following themselves,
+    /// it means the predicate is called on `slice[0]` and `slice[1]`
+    /// then on `slice[1]` and `slice[2]` and so on. A run ends where the
+    /// predicate returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &[1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// let mut iter = slice.group_by(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// This method can be used to extract the sorted subslices:
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &[1, 1, 2, 3, 2, 3, 2, 3, 4];
+    ///
+    /// let mut iter = slice.group_by(|a, b| a <= b);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3, 4][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[unstable(feature = "slice_group_by", issue = "none")]
+    #[inline]
+    pub fn group_by<F>(&self, pred: F) -> GroupBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        GroupBy { v: self, pred }
+    }
+
+    /// Returns an iterator over the slice producing non-overlapping mutable
+    /// runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called on two elements following themselves,
+    /// it means the predicate is called on `slice[0]` and `slice[1]`
+    /// then on `slice[1]` and `slice[2]` and so on. A run ends where the
+    /// predicate returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// let mut iter = slice.group_by_mut(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
+    /// assert_eq!(iter.next(), Some(&mut [3, 3][..]));
+    /// assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Replacing each run by its length, run-length encoding style:
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &mut [7, 7, 7, 0, 5, 5];
+    ///
+    /// for run in slice.group_by_mut(|a, b| a == b) {
+    ///     let len = run.len();
+    ///     run[0] = len;
+    /// }
+    ///
+    /// assert_eq!(slice, &[3, 7, 7, 1, 2, 5]);
+    /// ```
+    #[unstable(feature = "slice_group_by", issue = "none")]
+    #[inline]
+    pub fn group_by_mut<F>(&mut self, pred: F) -> GroupByMut<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        GroupByMut { v: self, pred }
+    }
+
     /// Divides one slice into two at an index.
     ///
     /// The first will contain all indices from `[0, mid)` (excluding
@@ -1803,7 +2022,7 @@ impl<T> [T] {
     /// This reordering has the additional property that any value at position `i < index` will be
     /// less than or equal to any value at a position `j > index`. Additionally, this reordering is
     /// unstable (i.e. any number of equal elements may end up at position `index`), in-place
-    /// (i.e. does not allocate), and *O*(*n*) worst-case. This function is also/ known as "kth
+    /// (i.e. does not allocate), and *O*(*n*) worst-case. This function is also known as "kth
     /// element" in other libraries. It returns a triplet of the following values: all elements less
     /// than the one at the given index, the value at the given index, and all elements greater than
     /// the one at the given index.
@@ -1822,12 +2041,12 @@ impl<T> [T] {
     /// # Examples
     ///
     /// ```
-    /// #![feature(slice_partition_at_index)]
+    /// #![feature(slice_select_nth_unstable)]
     ///
     /// let mut v = [-5i32, 4, 1, -3, 2];
     ///
     /// // Find the median
-    /// v.partition_at_index(2);
+    /// v.select_nth_unstable(2);
     ///
     /// // We are only guaranteed the slice will be one of the following, based on the way we sort
     /// // about the specified index.
@@ -1836,9 +2055,9 @@ impl<T> [T] {
     ///         v == [-3, -5, 1, 4, 2] ||
     ///         v == [-5, -3, 1, 4, 2]);
     /// ```
-    #[unstable(feature = "slice_partition_at_index", issue = "55300")]
+    #[unstable(feature = "slice_select_nth_unstable", issue = "55300")]
     #[inline]
-    pub fn partition_at_index(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
     where
         T: Ord,
     {
@@ -1872,12 +2091,12 @@ impl<T> [T] {
     /// # Examples
     ///
     /// ```
-    /// #![feature(slice_partition_at_index)]
+    /// #![feature(slice_select_nth_unstable)]
     ///
     /// let mut v = [-5i32, 4, 1, -3, 2];
     ///
     /// // Find the median as if the slice were sorted in descending order.
-    /// v.partition_at_index_by(2, |a, b| b.cmp(a));
+    /// v.select_nth_unstable_by(2, |a, b| b.cmp(a));
     ///
     /// // We are only guaranteed the slice will be one of the following, based on the way we sort
     /// // about the specified index.
@@ -1886,9 +2105,9 @@ impl<T> [T] {
     ///         v == [4, 2, 1, -5, -3] ||
     ///         v == [4, 2, 1, -3, -5]);
     /// ```
-    #[unstable(feature = "slice_partition_at_index", issue = "55300")]
+    #[unstable(feature = "slice_select_nth_unstable", issue = "55300")]
     #[inline]
-    pub fn partition_at_index_by<F>(
+    pub fn select_nth_unstable_by<F>(
         &mut self,
         index: usize,
         mut compare: F,
@@ -1926,12 +2145,12 @@ impl<T> [T] {
     /// # Examples
     ///
     /// ```
-    /// #![feature(slice_partition_at_index)]
+    /// #![feature(slice_select_nth_unstable)]
     ///
     /// let mut v = [-5i32, 4, 1, -3, 2];
     ///
     /// // Return the median as if the array were sorted according to absolute value.
-    /// v.partition_at_index_by_key(2, |a| a.abs());
+    /// v.select_nth_unstable_by_key(2, |a| a.abs());
     ///
     /// // We are only guaranteed the slice will be one of the following, based on the way we sort
     /// // about the specified index.
@@ -1940,9 +2159,9 @@ impl<T> [T] {
     ///         v == [2, 1, -3, 4, -5] ||
     ///         v == [2, 1, -3, -5, 4]);
     /// ```
-    #[unstable(feature = "slice_partition_at_index", issue = "55300")]
+    #[unstable(feature = "slice_select_nth_unstable", issue = "55300")]
     #[inline]
-    pub fn partition_at_index_by_key<K, F>(
+    pub fn select_nth_unstable_by_key<K, F>(
         &mut self,
         index: usize,
         mut f: F,
@@ -1955,6 +2174,50 @@ impl<T> [T] {
         sort::partition_at_index(self, index, &mut g)
     }
 
+    /// Reorder the slice such that the element at `index` is at its final sorted position.
+    #[unstable(feature = "slice_partition_at_index", issue = "55300")]
+    #[rustc_deprecated(since = "1.47.0", reason = "use select_nth_unstable() instead")]
+    #[inline]
+    pub fn partition_at_index(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        self.select_nth_unstable(index)
+    }
+
+    /// Reorder the slice with a comparator function such that the element at `index` is at its
+    /// final sorted position.
+    #[unstable(feature = "slice_partition_at_index", issue = "55300")]
+    #[rustc_deprecated(since = "1.47.0", reason = "use select_nth_unstable_by() instead")]
+    #[inline]
+    pub fn partition_at_index_by<F>(
+        &mut self,
+        index: usize,
+        compare: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.select_nth_unstable_by(index, compare)
+    }
+
+    /// Reorder the slice with a key extraction function such that the element at `index` is at its
+    /// final sorted position.
+    #[unstable(feature = "slice_partition_at_index", issue = "55300")]
+    #[rustc_deprecated(since = "1.47.0", reason = "use select_nth_unstable_by_key() instead")]
+    #[inline]
+    pub fn partition_at_index_by_key<K, F>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.select_nth_unstable_by_key(index, f)
+    }
+
     /// Moves all consecutive repeated elements to the end of the slice according to the
     /// [`PartialEq`] trait implementation.
     ///
@@ -2703,6 +2966,19 @@ impl<T> [T] {
     /// [`is_sorted`]; see its documentation for more information.
     ///
     /// [`is_sorted`]: #method.is_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    /// let empty: [i32; 0] = [];
+    ///
+    /// assert!([1, 2, 2, 9].is_sorted_by(|a, b| a.partial_cmp(b)));
+    /// assert!([9, 5, 5, 1].is_sorted_by(|a, b| b.partial_cmp(a)));
+    /// assert!(!["a", "B", "c"].is_sorted_by(|a, b| a.partial_cmp(b)));
+    /// assert!(["a", "B", "c"].is_sorted_by(|a, b| Some(a.to_lowercase().cmp(&b.to_lowercase()))));
+    /// assert!(empty.is_sorted_by(|_, _| None));
+    /// ```
     #[unstable(feature = "is_sorted", reason = "new API", issue = "53485")]
     pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
     where
@@ -2761,6 +3037,18 @@ impl<T> [T] {
     /// assert!(v[..i].iter().all(|&x| x < 5));
     /// assert!(v[i..].iter().all(|&x| !(x < 5)));
     /// ```
+    ///
+    /// Finding the range of elements equal to a given value in a sorted slice:
+    ///
+    /// ```
+    /// #![feature(partition_point)]
+    ///
+    /// let v = [1, 2, 3, 3, 3, 5, 6];
+    /// let start = v.partition_point(|&x| x < 3);
+    /// let end = v.partition_point(|&x| x <= 3);
+    ///
+    /// assert_eq!(&v[start..end], [3, 3, 3]);
+    /// ```
     #[unstable(feature = "partition_point", reason = "new API", issue = "73831")]
     pub fn partition_point<P>(&self, mut pred: P) -> usize
     where
@@ -2774,7 +3062,7 @@ impl<T> [T] {
             // SAFETY:
             // When left < right, left <= mid < right.
             // Therefore left always increases and right always decreases,
-            // and eigher of them is selected.
+            // and either of them is selected.
             // In both cases left <= right is satisfied.
             // Therefore if left < right in a step,
             // left <= right is satisfied in the next step.
@@ -2845,6 +3133,203 @@ impl [u8] {
             byte.make_ascii_lowercase();
         }
     }
+
+    /// Returns the index of the first occurrence of `needle` in this slice,
+    /// or `None` if it does not occur.
+    ///
+    /// An empty `needle` is found at index 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_pattern)]
+    ///
+    /// let haystack = b"\x00\x01GIF89a\x00GIF89a";
+    /// assert_eq!(haystack.find(b"GIF"), Some(2));
+    /// assert_eq!(haystack.find(b"PNG"), None);
+    /// assert_eq!(haystack.find(b""), Some(0));
+    /// ```
+    #[unstable(feature = "byte_slice_pattern", issue = "none")]
+    #[inline]
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        BytesSearcher::new(self, needle).next_match().map(|(start, _)| start)
+    }
+
+    /// Returns the index of the last occurrence of `needle` in this slice,
+    /// or `None` if it does not occur.
+    ///
+    /// An empty `needle` is found at the end of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_pattern)]
+    ///
+    /// let haystack = b"\x00\x01GIF89a\x00GIF89a";
+    /// assert_eq!(haystack.rfind(b"GIF"), Some(9));
+    /// assert_eq!(haystack.rfind(b"PNG"), None);
+    /// assert_eq!(haystack.rfind(b""), Some(haystack.len()));
+    /// ```
+    #[unstable(feature = "byte_slice_pattern", issue = "none")]
+    #[inline]
+    pub fn rfind(&self, needle: &[u8]) -> Option<usize> {
+        BytesSearcher::new(self, needle).next_match_back().map(|(start, _)| start)
+    }
+
+    /// Returns an iterator over subslices of this slice, separated by
+    /// occurrences of `needle`. The matched bytes are not contained in the
+    /// subslices.
+    ///
+    /// This is the byte slice equivalent of [`str::split`] with a `&str`
+    /// pattern: occurrences are found from the front without overlapping, and
+    /// an empty `needle` splits the slice before and after every byte.
+    ///
+    /// [`str::split`]: ../std/primitive.str.html#method.split
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_pattern)]
+    ///
+    /// let request = b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n";
+    /// let mut lines = request.split_pattern(b"\r\n");
+    /// assert_eq!(lines.next(), Some(&b"GET / HTTP/1.1"[..]));
+    /// assert_eq!(lines.next(), Some(&b"Host: example.com"[..]));
+    /// assert_eq!(lines.next(), Some(&b""[..]));
+    /// assert_eq!(lines.next(), Some(&b""[..]));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    #[unstable(feature = "byte_slice_pattern", issue = "none")]
+    #[inline]
+    pub fn split_pattern<'a, 'b>(&'a self, needle: &'b [u8]) -> SplitPattern<'a, 'b> {
+        SplitPattern { searcher: BytesSearcher::new(self, needle), start: 0, finished: false }
+    }
+
+    /// Returns an iterator over the lines of this slice, as byte slices.
+    ///
+    /// Lines are ended with either a newline (`\n`) or a carriage return
+    /// followed by a line feed (`\r\n`), like with [`str::lines`]. The final
+    /// line ending is optional. Unlike `str::lines`, the lines don't have to
+    /// be valid UTF-8.
+    ///
+    /// This isn't called `lines`, as that would shadow [`BufRead::lines`],
+    /// which `&[u8]` also has and which yields owned `String`s.
+    ///
+    /// [`str::lines`]: ../std/primitive.str.html#method.lines
+    /// [`BufRead::lines`]: ../std/io/trait.BufRead.html#method.lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_lines)]
+    ///
+    /// let text = b"foo\r\nbar\n\nb\xffz\n";
+    /// let mut lines = text.byte_lines();
+    /// assert_eq!(lines.next(), Some(&b"foo"[..]));
+    /// assert_eq!(lines.next(), Some(&b"bar"[..]));
+    /// assert_eq!(lines.next(), Some(&b""[..]));
+    /// assert_eq!(lines.next(), Some(&b"b\xffz"[..]));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    #[unstable(feature = "byte_slice_lines", issue = "none")]
+    #[inline]
+    pub fn byte_lines(&self) -> ByteLines<'_> {
+        ByteLines { v: self }
+    }
+
+    /// Returns a byte slice with leading ASCII whitespace bytes removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// [`u8::is_ascii_whitespace`].
+    ///
+    /// [`u8::is_ascii_whitespace`]: ../std/primitive.u8.html#method.is_ascii_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(b" \t hello world\n".trim_ascii_start(), b"hello world\n");
+    /// assert_eq!(b"  ".trim_ascii_start(), b"");
+    /// assert_eq!(b"".trim_ascii_start(), b"");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "none")]
+    #[inline]
+    pub fn trim_ascii_start(&self) -> &[u8] {
+        let start = self.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(self.len());
+        &self[start..]
+    }
+
+    /// Returns a byte slice with trailing ASCII whitespace bytes removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// [`u8::is_ascii_whitespace`].
+    ///
+    /// [`u8::is_ascii_whitespace`]: ../std/primitive.u8.html#method.is_ascii_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(b"\r hello world\n ".trim_ascii_end(), b"\r hello world");
+    /// assert_eq!(b"  ".trim_ascii_end(), b"");
+    /// assert_eq!(b"".trim_ascii_end(), b"");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "none")]
+    #[inline]
+    pub fn trim_ascii_end(&self) -> &[u8] {
+        let end = self.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(0, |i| i + 1);
+        &self[..end]
+    }
+
+    /// Returns a byte slice with leading and trailing ASCII whitespace bytes
+    /// removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// [`u8::is_ascii_whitespace`].
+    ///
+    /// [`u8::is_ascii_whitespace`]: ../std/primitive.u8.html#method.is_ascii_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(b"\r hello world\n ".trim_ascii(), b"hello world");
+    /// assert_eq!(b"  ".trim_ascii(), b"");
+    /// assert_eq!(b"".trim_ascii(), b"");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "none")]
+    #[inline]
+    pub fn trim_ascii(&self) -> &[u8] {
+        self.trim_ascii_start().trim_ascii_end()
+    }
+
+    /// Returns an iterator that produces an escaped version of this slice,
+    /// treating it as an ASCII string.
+    ///
+    /// Each byte is escaped with [`ascii::escape_default`], so the result is
+    /// printable ASCII. The iterator also implements `Display`, which makes it
+    /// handy for showing binary data in messages.
+    ///
+    /// [`ascii::escape_default`]: ../std/ascii/fn.escape_default.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(inherent_ascii_escape)]
+    ///
+    /// let s = b"0\t\r\n'\"\\\x9d";
+    /// let escaped = s.escape_ascii().to_string();
+    /// assert_eq!(escaped, "0\\t\\r\\n\\'\\\"\\\\\\x9d");
+    /// ```
+    #[unstable(feature = "inherent_ascii_escape", issue = "none")]
+    #[inline]
+    pub fn escape_ascii(&self) -> EscapeAscii<'_> {
+        let escape: fn(&u8) -> ascii::EscapeDefault = |&byte| ascii::escape_default(byte);
+        EscapeAscii { inner: self.iter().flat_map(escape) }
+    }
 }
 
 /// Returns `true` if any byte in the word `v` is nonascii (>= 128). Snarfed
@@ -5432,8 +5917,217 @@ unsafe impl<'a, T> TrustedRandomAccess for ChunksExactMut<'a, T> {
     }
 }
 
-/// An iterator over a slice in (non-overlapping) chunks (`chunk_size` elements at a
-/// time), starting at the end of the slice.
+/// An iterator over a slice in (non-overlapping) chunks (`N` elements at a
+/// time), starting at the beginning of the slice.
+///
+/// When the slice len is not evenly divided by the chunk size, the last
+/// up to `N-1` elements will be omitted but can be retrieved from
+/// the [`remainder`] function from the iterator.
+///
+/// This struct is created by the [`array_chunks`] method on [slices].
+///
+/// [`array_chunks`]: ../../std/primitive.slice.html#method.array_chunks
+/// [`remainder`]: ../../std/slice/struct.ArrayChunks.html#method.remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "array_chunks", issue = "none")]
+pub struct ArrayChunks<'a, T: 'a, const N: usize> {
+    iter: Iter<'a, [T; N]>,
+    rem: &'a [T],
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `N-1`
+    /// elements.
+    #[unstable(feature = "array_chunks", issue = "none")]
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "array_chunks", issue = "none")]
+impl<T, const N: usize> Clone for ArrayChunks<'_, T, N> {
+    fn clone(&self) -> Self {
+        ArrayChunks { iter: self.iter.clone(), rem: self.rem }
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "none")]
+impl<'a, T, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T; N]> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last()
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "none")]
+impl<'a, T, const N: usize> DoubleEndedIterator for ArrayChunks<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T; N]> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "none")]
+impl<T, const N: usize> ExactSizeIterator for ArrayChunks<'_, T, N> {
+    fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<T, const N: usize> TrustedLen for ArrayChunks<'_, T, N> {}
+
+#[unstable(feature = "array_chunks", issue = "none")]
+impl<T, const N: usize> FusedIterator for ArrayChunks<'_, T, N> {}
+
+#[doc(hidden)]
+#[unstable(feature = "array_chunks", issue = "none")]
+unsafe impl<'a, T, const N: usize> TrustedRandomAccess for ArrayChunks<'a, T, N> {
+    unsafe fn get_unchecked(&mut self, i: usize) -> &'a [T; N] {
+        // SAFETY: the caller guarantees that `i` is in bounds, which is all
+        // `Iter::get_unchecked` needs.
+        unsafe { self.iter.get_unchecked(i) }
+    }
+    fn may_have_side_effect() -> bool {
+        false
+    }
+}
+
+/// An iterator over overlapping windows of `N` elements of a slice, starting
+/// at the beginning of the slice.
+///
+/// This struct is created by the [`array_windows`] method on [slices].
+///
+/// [`array_windows`]: ../../std/primitive.slice.html#method.array_windows
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "array_windows", issue = "none")]
+pub struct ArrayWindows<'a, T: 'a, const N: usize> {
+    // Invariant: `N` is not 0.
+    v: &'a [T],
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "array_windows", issue = "none")]
+impl<T, const N: usize> Clone for ArrayWindows<'_, T, N> {
+    fn clone(&self) -> Self {
+        ArrayWindows { v: self.v }
+    }
+}
+
+#[unstable(feature = "array_windows", issue = "none")]
+impl<'a, T, const N: usize> Iterator for ArrayWindows<'a, T, N> {
+    type Item = &'a [T; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T; N]> {
+        if self.v.len() < N {
+            None
+        } else {
+            // SAFETY: `v` has at least `N` elements, and `[T; N]` is laid out
+            // as `N` consecutive `T`s.
+            let window = unsafe { &*(self.v.as_ptr() as *const [T; N]) };
+            self.v = &self.v[1..];
+            Some(window)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.v.len() < N { 0 } else { self.v.len() - N + 1 };
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.v = &[];
+            None
+        } else {
+            self.v = &self.v[n..];
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "array_windows", issue = "none")]
+impl<'a, T, const N: usize> DoubleEndedIterator for ArrayWindows<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T; N]> {
+        if self.v.len() < N {
+            None
+        } else {
+            let len = self.v.len();
+            // SAFETY: the last `N` elements of `v` are in bounds, see `next`.
+            let window = unsafe { &*(self.v[len - N..].as_ptr() as *const [T; N]) };
+            self.v = &self.v[..len - 1];
+            Some(window)
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.v = &[];
+            None
+        } else {
+            self.v = &self.v[..self.v.len() - n];
+            self.next_back()
+        }
+    }
+}
+
+#[unstable(feature = "array_windows", issue = "none")]
+impl<T, const N: usize> ExactSizeIterator for ArrayWindows<'_, T, N> {}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<T, const N: usize> TrustedLen for ArrayWindows<'_, T, N> {}
+
+#[unstable(feature = "array_windows", issue = "none")]
+impl<T, const N: usize> FusedIterator for ArrayWindows<'_, T, N> {}
+
+/// An iterator over a slice in (non-overlapping) chunks (`chunk_size` elements at a
+/// time), starting at the end of the slice.
 ///
 /// When the slice len is not evenly divided by the chunk size, the last slice
 /// of the iteration will be the remainder.
@@ -6008,6 +6702,359 @@ unsafe impl<'a, T> TrustedRandomAccess for RChunksExactMut<'a, T> {
     }
 }
 
+/// An iterator over slice in (non-overlapping) chunks separated by a predicate.
+///
+/// This struct is created by the [`group_by`] method on [slices].
+///
+/// [`group_by`]: ../../std/primitive.slice.html#method.group_by
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "slice_group_by", issue = "none")]
+pub struct GroupBy<'a, T: 'a, P> {
+    v: &'a [T],
+    pred: P,
+}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a, P> Iterator for GroupBy<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            let mut iter = self.v.windows(2);
+            while let Some([l, r]) = iter.next() {
+                if (self.pred)(l, r) { len += 1 } else { break }
+            }
+            let (head, tail) = self.v.split_at(len);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.v.len()))
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a, P> DoubleEndedIterator for GroupBy<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            let mut iter = self.v.windows(2);
+            while let Some([l, r]) = iter.next_back() {
+                if (self.pred)(l, r) { len += 1 } else { break }
+            }
+            let (head, tail) = self.v.split_at(self.v.len() - len);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a, P> FusedIterator for GroupBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for GroupBy<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupBy").field("v", &self.v).finish()
+    }
+}
+
+/// An iterator over slice in (non-overlapping) mutable chunks separated
+/// by a predicate.
+///
+/// This struct is created by the [`group_by_mut`] method on [slices].
+///
+/// [`group_by_mut`]: ../../std/primitive.slice.html#method.group_by_mut
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "slice_group_by", issue = "none")]
+pub struct GroupByMut<'a, T: 'a, P> {
+    v: &'a mut [T],
+    pred: P,
+}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a, P> Iterator for GroupByMut<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            let mut iter = self.v.windows(2);
+            while let Some([l, r]) = iter.next() {
+                if (self.pred)(l, r) { len += 1 } else { break }
+            }
+            let slice = mem::take(&mut self.v);
+            let (head, tail) = slice.split_at_mut(len);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.v.len()))
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a, P> DoubleEndedIterator for GroupByMut<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            let mut iter = self.v.windows(2);
+            while let Some([l, r]) = iter.next_back() {
+                if (self.pred)(l, r) { len += 1 } else { break }
+            }
+            let slice = mem::take(&mut self.v);
+            let (head, tail) = slice.split_at_mut(slice.len() - len);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a, P> FusedIterator for GroupByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+#[unstable(feature = "slice_group_by", issue = "none")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for GroupByMut<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupByMut").field("v", &self.v).finish()
+    }
+}
+
+/// An iterator over subslices of a byte slice, separated by occurrences of a
+/// byte string.
+///
+/// This struct is created by the [`split_pattern`] method on [slices].
+///
+/// [`split_pattern`]: ../../std/primitive.slice.html#method.split_pattern
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Clone)]
+#[unstable(feature = "byte_slice_pattern", issue = "none")]
+pub struct SplitPattern<'a, 'b> {
+    searcher: BytesSearcher<'a, 'b>,
+    start: usize,
+    finished: bool,
+}
+
+#[unstable(feature = "byte_slice_pattern", issue = "none")]
+impl<'a, 'b> Iterator for SplitPattern<'a, 'b> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.finished {
+            return None;
+        }
+        let haystack = self.searcher.haystack();
+        match self.searcher.next_match() {
+            Some((a, b)) => {
+                let elt = &haystack[self.start..a];
+                self.start = b;
+                Some(elt)
+            }
+            None => {
+                self.finished = true;
+                Some(&haystack[self.start..])
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // an empty needle splits before and after every remaining byte
+            (1, Some(self.searcher.haystack().len() - self.start + 2))
+        }
+    }
+}
+
+#[unstable(feature = "byte_slice_pattern", issue = "none")]
+impl FusedIterator for SplitPattern<'_, '_> {}
+
+#[unstable(feature = "byte_slice_pattern", issue = "none")]
+impl fmt::Debug for SplitPattern<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitPattern")
+            .field("v", &&self.searcher.haystack()[self.start..])
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+/// An iterator over the lines of a byte slice.
+///
+/// This struct is created by the [`byte_lines`] method on [slices].
+///
+/// [`byte_lines`]: ../../std/primitive.slice.html#method.byte_lines
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Clone, Debug)]
+#[unstable(feature = "byte_slice_lines", issue = "none")]
+pub struct ByteLines<'a> {
+    v: &'a [u8],
+}
+
+/// Strips the `\r` of a `\r\n` line ending from a line.
+#[inline]
+fn strip_cr(line: &[u8]) -> &[u8] {
+    match line {
+        [line @ .., b'\r'] => line,
+        _ => line,
+    }
+}
+
+#[unstable(feature = "byte_slice_lines", issue = "none")]
+impl<'a> Iterator for ByteLines<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.v.is_empty() {
+            return None;
+        }
+        let (line, rest) = match memchr::memchr(b'\n', self.v) {
+            Some(i) => (&self.v[..i], &self.v[i + 1..]),
+            None => (self.v, &[][..]),
+        };
+        self.v = rest;
+        Some(strip_cr(line))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() { (0, Some(0)) } else { (1, Some(self.v.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "byte_slice_lines", issue = "none")]
+impl<'a> DoubleEndedIterator for ByteLines<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [u8]> {
+        // the line ending of the last line is optional
+        let body = match self.v {
+            [] => return None,
+            [body @ .., b'\n'] => body,
+            body => body,
+        };
+        let (rest, line) = match memchr::memrchr(b'\n', body) {
+            Some(i) => (&body[..i + 1], &body[i + 1..]),
+            None => (&[][..], body),
+        };
+        self.v = rest;
+        Some(strip_cr(line))
+    }
+}
+
+#[unstable(feature = "byte_slice_lines", issue = "none")]
+impl FusedIterator for ByteLines<'_> {}
+
+/// An iterator over the escaped version of a byte slice.
+///
+/// This struct is created by the [`escape_ascii`] method on [slices].
+///
+/// [`escape_ascii`]: ../../std/primitive.slice.html#method.escape_ascii
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Clone)]
+#[unstable(feature = "inherent_ascii_escape", issue = "none")]
+pub struct EscapeAscii<'a> {
+    inner: FlatMap<Iter<'a, u8>, ascii::EscapeDefault, fn(&u8) -> ascii::EscapeDefault>,
+}
+
+#[unstable(feature = "inherent_ascii_escape", issue = "none")]
+impl<'a> Iterator for EscapeAscii<'a> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "inherent_ascii_escape", issue = "none")]
+impl<'a> DoubleEndedIterator for EscapeAscii<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        self.inner.next_back()
+    }
+}
+
+#[unstable(feature = "inherent_ascii_escape", issue = "none")]
+impl FusedIterator for EscapeAscii<'_> {}
+
+#[unstable(feature = "inherent_ascii_escape", issue = "none")]
+impl fmt::Display for EscapeAscii<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the escaped bytes are all printable ASCII
+        self.clone().try_for_each(|byte| f.write_char(byte as char))
+    }
+}
+
+#[unstable(feature = "inherent_ascii_escape", issue = "none")]
+impl fmt::Debug for EscapeAscii<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("EscapeAscii { .. }")
+    }
+}
+
 //
 // Free functions
 //
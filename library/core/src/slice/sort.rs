@@ -839,7 +839,7 @@ where
     use cmp::Ordering::Less;
 
     if index >= v.len() {
-        panic!("partition_at_index index {} greater than length of slice {}", index, v.len());
+        panic!("select_nth_unstable index {} greater than length of slice {}", index, v.len());
     }
 
     if mem::size_of::<T>() == 0 {
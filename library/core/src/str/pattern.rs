@@ -73,9 +73,11 @@ use crate::slice::memchr;
 /// | `F: FnMut(char) -> bool` | `F` returns `true` for a char in string   |
 /// | `&&str`                  | is substring                              |
 /// | `&String`                | is substring                              |
+/// | `[&str; N]`              | any of the strings is a substring         |
 ///
 /// # Examples
 /// ```
+/// # #![feature(str_multi_pattern)]
 /// // &str
 /// assert_eq!("abaaa".find("ba"), Some(1));
 /// assert_eq!("abaaa".find("bac"), None);
@@ -93,6 +95,10 @@ use crate::slice::memchr;
 /// // FnMut(char) -> bool
 /// assert_eq!("abcdef_z".find(|ch| ch > 'd' && ch < 'y'), Some(4));
 /// assert_eq!("abcddd_z".find(|ch| ch > 'd' && ch < 'y'), None);
+///
+/// // [&str; N]
+/// assert_eq!("abaaa".find(["aa", "ba"]), Some(1));
+/// assert_eq!("abaaa".find(["ca", "bc"]), None);
 /// ```
 ///
 /// [`str::find`]: ../../../std/primitive.str.html#method.find
@@ -903,6 +909,284 @@ impl<'a, 'b> Pattern<'a> for &'b str {
     }
 }
 
+/////////////////////////////////////////////////////////////////////////////
+// Impl for [&str; N]
+/////////////////////////////////////////////////////////////////////////////
+
+/// Searches for any of several substrings at once.
+///
+/// Matches are found leftmost-first: the searcher reports the match that
+/// starts earliest in the haystack, and if several needles match there, the
+/// one that comes first in the array wins. Searching from the back reports
+/// the match that ends last, again preferring needles earlier in the array.
+///
+/// Empty needles are handled like the pattern `""`, returning empty matches at
+/// each character boundary where no earlier needle matches.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_multi_pattern)]
+///
+/// let s = "the cat sat on the mat";
+/// assert_eq!(s.find(["mat", "sat"]), Some(8));
+/// assert_eq!(s.rfind(["mat", "sat"]), Some(19));
+///
+/// // At the same position, needles earlier in the array are preferred.
+/// let v: Vec<&str> = "category".matches(["cat", "category"]).collect();
+/// assert_eq!(v, ["cat"]);
+///
+/// let v: Vec<&str> = "a-b_c".split(["-", "_"]).collect();
+/// assert_eq!(v, ["a", "b", "c"]);
+/// ```
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+impl<'a, 'b, const N: usize> Pattern<'a> for [&'b str; N] {
+    type Searcher = MultiStrSearcher<'a, 'b, N>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a str) -> MultiStrSearcher<'a, 'b, N> {
+        MultiStrSearcher::new(haystack, self)
+    }
+
+    /// Checks whether the pattern matches at the front of the haystack.
+    #[inline]
+    fn is_prefix_of(self, haystack: &'a str) -> bool {
+        self.iter().any(|needle| haystack.as_bytes().starts_with(needle.as_bytes()))
+    }
+
+    /// Checks whether the pattern matches at the back of the haystack.
+    #[inline]
+    fn is_suffix_of(self, haystack: &'a str) -> bool {
+        self.iter().any(|needle| haystack.as_bytes().ends_with(needle.as_bytes()))
+    }
+}
+
+/// Associated type for `<[&str; N] as Pattern<'a>>::Searcher`.
+#[derive(Clone, Debug)]
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+pub struct MultiStrSearcher<'a, 'b, const N: usize> {
+    haystack: &'a str,
+    needles: [&'b str; N],
+
+    position: usize,
+    end: usize,
+    is_match_fw: bool,
+    is_match_bw: bool,
+    has_empty: bool,
+    /// The first bytes of the needles, used to skip ahead to candidates.
+    first_bytes: ByteSet,
+    /// The last bytes of the needles, used the same way from the back.
+    last_bytes: ByteSet,
+}
+
+impl<'a, 'b, const N: usize> MultiStrSearcher<'a, 'b, N> {
+    fn new(haystack: &'a str, needles: [&'b str; N]) -> MultiStrSearcher<'a, 'b, N> {
+        let mut has_empty = false;
+        let mut first_bytes = ByteSet::new();
+        let mut last_bytes = ByteSet::new();
+        for needle in needles.iter() {
+            match (needle.as_bytes().first(), needle.as_bytes().last()) {
+                (Some(&first), Some(&last)) => {
+                    first_bytes.insert(first);
+                    last_bytes.insert(last);
+                }
+                _ => has_empty = true,
+            }
+        }
+        MultiStrSearcher {
+            haystack,
+            needles,
+            position: 0,
+            end: haystack.len(),
+            is_match_fw: true,
+            is_match_bw: true,
+            has_empty,
+            first_bytes,
+            last_bytes,
+        }
+    }
+
+    /// Returns the length of the first needle that matches at `pos`.
+    #[inline]
+    fn match_at(&self, pos: usize) -> Option<usize> {
+        let bytes = &self.haystack.as_bytes()[pos..self.end];
+        self.needles.iter().find(|n| bytes.starts_with(n.as_bytes())).map(|n| n.len())
+    }
+
+    /// Returns the length of the first needle that matches ending at `end`.
+    #[inline]
+    fn match_back_at(&self, end: usize) -> Option<usize> {
+        let bytes = &self.haystack.as_bytes()[self.position..end];
+        self.needles.iter().find(|n| bytes.ends_with(n.as_bytes())).map(|n| n.len())
+    }
+}
+
+unsafe impl<'a, 'b, const N: usize> Searcher<'a> for MultiStrSearcher<'a, 'b, N> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let pos = self.position;
+        if self.is_match_fw {
+            if let Some(len) = self.match_at(pos) {
+                if len == 0 {
+                    // step over a char before matching here again
+                    self.is_match_fw = false;
+                } else {
+                    self.position += len;
+                }
+                return SearchStep::Match(pos, pos + len);
+            }
+        }
+        match self.haystack[pos..self.end].chars().next() {
+            Some(ch) => {
+                self.is_match_fw = true;
+                self.position += ch.len_utf8();
+                SearchStep::Reject(pos, self.position)
+            }
+            None => SearchStep::Done,
+        }
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        if self.has_empty {
+            // every position is a candidate
+            loop {
+                match self.next() {
+                    SearchStep::Match(a, b) => return Some((a, b)),
+                    SearchStep::Done => return None,
+                    SearchStep::Reject(..) => {}
+                }
+            }
+        }
+        loop {
+            let pos = self.position;
+            let bytes = &self.haystack.as_bytes()[pos..self.end];
+            // The first byte of a needle is never a UTF-8 continuation byte, so
+            // every candidate is at a char boundary.
+            let candidate = match self.first_bytes.find(bytes) {
+                Some(index) => pos + index,
+                None => {
+                    self.position = self.end;
+                    return None;
+                }
+            };
+            if let Some(len) = self.match_at(candidate) {
+                self.position = candidate + len;
+                return Some((candidate, candidate + len));
+            }
+            let width = super::utf8_char_width(self.haystack.as_bytes()[candidate]);
+            self.position = candidate + width;
+        }
+    }
+}
+
+unsafe impl<'a, 'b, const N: usize> ReverseSearcher<'a> for MultiStrSearcher<'a, 'b, N> {
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let end = self.end;
+        if self.is_match_bw {
+            if let Some(len) = self.match_back_at(end) {
+                if len == 0 {
+                    self.is_match_bw = false;
+                } else {
+                    self.end -= len;
+                }
+                return SearchStep::Match(end - len, end);
+            }
+        }
+        match self.haystack[self.position..end].chars().next_back() {
+            Some(ch) => {
+                self.is_match_bw = true;
+                self.end -= ch.len_utf8();
+                SearchStep::Reject(self.end, end)
+            }
+            None => SearchStep::Done,
+        }
+    }
+
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        if self.has_empty {
+            loop {
+                match self.next_back() {
+                    SearchStep::Match(a, b) => return Some((a, b)),
+                    SearchStep::Done => return None,
+                    SearchStep::Reject(..) => {}
+                }
+            }
+        }
+        loop {
+            let bytes = &self.haystack.as_bytes()[self.position..self.end];
+            let candidate = match self.last_bytes.rfind(bytes) {
+                Some(index) => self.position + index + 1,
+                None => {
+                    self.end = self.position;
+                    return None;
+                }
+            };
+            // A whole needle ending at `candidate` ends at a char boundary.
+            if let Some(len) = self.match_back_at(candidate) {
+                self.end = candidate - len;
+                return Some((candidate - len, candidate));
+            }
+            // Otherwise, back up to the start of the char the candidate is in.
+            let mut end = candidate - 1;
+            while !self.haystack.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.end = end;
+        }
+    }
+}
+
+/// A set of bytes, which searches with `memchr` when it only holds one.
+#[derive(Clone, Debug)]
+struct ByteSet {
+    bits: [u64; 4],
+    single: Option<u8>,
+}
+
+impl ByteSet {
+    fn new() -> ByteSet {
+        ByteSet { bits: [0; 4], single: None }
+    }
+
+    fn insert(&mut self, byte: u8) {
+        if self.bits == [0; 4] {
+            self.single = Some(byte);
+        } else if self.single != Some(byte) {
+            self.single = None;
+        }
+        self.bits[(byte / 64) as usize] |= 1 << (byte % 64);
+    }
+
+    #[inline]
+    fn contains(&self, byte: u8) -> bool {
+        self.bits[(byte / 64) as usize] & (1 << (byte % 64)) != 0
+    }
+
+    #[inline]
+    fn find(&self, bytes: &[u8]) -> Option<usize> {
+        match self.single {
+            Some(byte) => memchr::memchr(byte, bytes),
+            None => bytes.iter().position(|&b| self.contains(b)),
+        }
+    }
+
+    #[inline]
+    fn rfind(&self, bytes: &[u8]) -> Option<usize> {
+        match self.single {
+            Some(byte) => memchr::memrchr(byte, bytes),
+            None => bytes.iter().rposition(|&b| self.contains(b)),
+        }
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Two Way substring searcher
 /////////////////////////////////////////////////////////////////////////////
@@ -919,6 +1203,7 @@ pub struct StrSearcher<'a, 'b> {
 #[derive(Clone, Debug)]
 enum StrSearcherImpl {
     Empty(EmptyNeedle),
+    Byte(ByteNeedle),
     TwoWay(TwoWaySearcher),
 }
 
@@ -943,6 +1228,14 @@ impl<'a, 'b> StrSearcher<'a, 'b> {
                     is_match_bw: true,
                 }),
             }
+        } else if let [byte] = *needle.as_bytes() {
+            // A one byte needle is an ASCII char, which can only be found at char
+            // boundaries, so the search can be handed over to `memchr`.
+            StrSearcher {
+                haystack,
+                needle,
+                searcher: StrSearcherImpl::Byte(ByteNeedle::new(byte, haystack.len())),
+            }
         } else {
             StrSearcher {
                 haystack,
@@ -979,6 +1272,7 @@ unsafe impl<'a, 'b> Searcher<'a> for StrSearcher<'a, 'b> {
                     }
                 }
             }
+            StrSearcherImpl::Byte(ref mut searcher) => searcher.next(self.haystack.as_bytes()),
             StrSearcherImpl::TwoWay(ref mut searcher) => {
                 // TwoWaySearcher produces valid *Match* indices that split at char boundaries
                 // as long as it does correct matching and that haystack and needle are
@@ -1018,6 +1312,9 @@ unsafe impl<'a, 'b> Searcher<'a> for StrSearcher<'a, 'b> {
                     SearchStep::Reject(..) => {}
                 }
             },
+            StrSearcherImpl::Byte(ref mut searcher) => {
+                searcher.next_match(self.haystack.as_bytes())
+            }
             StrSearcherImpl::TwoWay(ref mut searcher) => {
                 let is_long = searcher.memory == usize::MAX;
                 // write out `true` and `false` cases to encourage the compiler
@@ -1057,6 +1354,7 @@ unsafe impl<'a, 'b> ReverseSearcher<'a> for StrSearcher<'a, 'b> {
                     }
                 }
             }
+            StrSearcherImpl::Byte(ref mut searcher) => searcher.next_back(self.haystack.as_bytes()),
             StrSearcherImpl::TwoWay(ref mut searcher) => {
                 if searcher.end == 0 {
                     return SearchStep::Done;
@@ -1091,6 +1389,9 @@ unsafe impl<'a, 'b> ReverseSearcher<'a> for StrSearcher<'a, 'b> {
                     SearchStep::Reject(..) => {}
                 }
             },
+            StrSearcherImpl::Byte(ref mut searcher) => {
+                searcher.next_match_back(self.haystack.as_bytes())
+            }
             StrSearcherImpl::TwoWay(ref mut searcher) => {
                 let is_long = searcher.memory == usize::MAX;
                 // write out `true` and `false`, like `next_match`
@@ -1112,6 +1413,186 @@ unsafe impl<'a, 'b> ReverseSearcher<'a> for StrSearcher<'a, 'b> {
     }
 }
 
+/// The state of a search for a single byte, which is done with `memchr`.
+///
+/// Forward searches move `position` up and reverse searches move `end` down,
+/// so matches found from the two ends never overlap.
+#[derive(Clone, Debug)]
+struct ByteNeedle {
+    byte: u8,
+    position: usize,
+    end: usize,
+}
+
+impl ByteNeedle {
+    fn new(byte: u8, end: usize) -> ByteNeedle {
+        ByteNeedle { byte, position: 0, end }
+    }
+
+    #[inline]
+    fn next(&mut self, haystack: &[u8]) -> SearchStep {
+        let pos = self.position;
+        if pos == self.end {
+            return SearchStep::Done;
+        }
+        if haystack[pos] == self.byte {
+            self.position += 1;
+            return SearchStep::Match(pos, pos + 1);
+        }
+        // Reject everything up to the next occurrence in one step.
+        self.position = match memchr::memchr(self.byte, &haystack[pos..self.end]) {
+            Some(index) => pos + index,
+            None => self.end,
+        };
+        SearchStep::Reject(pos, self.position)
+    }
+
+    #[inline]
+    fn next_match(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        match memchr::memchr(self.byte, &haystack[self.position..self.end]) {
+            Some(index) => {
+                let found = self.position + index;
+                self.position = found + 1;
+                Some((found, found + 1))
+            }
+            None => {
+                self.position = self.end;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn next_back(&mut self, haystack: &[u8]) -> SearchStep {
+        let end = self.end;
+        if end == self.position {
+            return SearchStep::Done;
+        }
+        if haystack[end - 1] == self.byte {
+            self.end -= 1;
+            return SearchStep::Match(end - 1, end);
+        }
+        self.end = match memchr::memrchr(self.byte, &haystack[self.position..end]) {
+            Some(index) => self.position + index + 1,
+            None => self.position,
+        };
+        SearchStep::Reject(self.end, end)
+    }
+
+    #[inline]
+    fn next_match_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+        match memchr::memrchr(self.byte, &haystack[self.position..self.end]) {
+            Some(index) => {
+                let found = self.position + index;
+                self.end = found;
+                Some((found, found + 1))
+            }
+            None => {
+                self.end = self.position;
+                None
+            }
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Substring searcher for byte slices
+/////////////////////////////////////////////////////////////////////////////
+
+/// Non-allocating substring search in byte slices, backing the search methods
+/// of `[u8]`.
+///
+/// This is the same search as the one for `&str` patterns, except that matches
+/// can start at any index. The empty needle matches at every index, including
+/// the end of the haystack.
+#[derive(Clone, Debug)]
+pub(crate) struct BytesSearcher<'a, 'b> {
+    haystack: &'a [u8],
+    needle: &'b [u8],
+
+    searcher: BytesSearcherImpl,
+}
+
+#[derive(Clone, Debug)]
+enum BytesSearcherImpl {
+    Empty { position: usize, end: usize, exhausted: bool },
+    Byte(ByteNeedle),
+    TwoWay(TwoWaySearcher),
+}
+
+impl<'a, 'b> BytesSearcher<'a, 'b> {
+    pub(crate) fn new(haystack: &'a [u8], needle: &'b [u8]) -> BytesSearcher<'a, 'b> {
+        let searcher = match *needle {
+            [] => BytesSearcherImpl::Empty { position: 0, end: haystack.len(), exhausted: false },
+            [byte] => BytesSearcherImpl::Byte(ByteNeedle::new(byte, haystack.len())),
+            _ => BytesSearcherImpl::TwoWay(TwoWaySearcher::new(needle, haystack.len())),
+        };
+        BytesSearcher { haystack, needle, searcher }
+    }
+
+    #[inline]
+    pub(crate) fn haystack(&self) -> &'a [u8] {
+        self.haystack
+    }
+
+    /// Finds the next match from the front, as a range of indices.
+    #[inline]
+    pub(crate) fn next_match(&mut self) -> Option<(usize, usize)> {
+        match self.searcher {
+            BytesSearcherImpl::Empty { ref mut position, end, ref mut exhausted } => {
+                if *exhausted {
+                    return None;
+                }
+                let pos = *position;
+                if pos == end {
+                    *exhausted = true;
+                } else {
+                    *position += 1;
+                }
+                Some((pos, pos))
+            }
+            BytesSearcherImpl::Byte(ref mut searcher) => searcher.next_match(self.haystack),
+            BytesSearcherImpl::TwoWay(ref mut searcher) => {
+                let is_long = searcher.memory == usize::MAX;
+                // write out `true` and `false`, like `StrSearcher::next_match`
+                if is_long {
+                    searcher.next::<MatchOnly>(self.haystack, self.needle, true)
+                } else {
+                    searcher.next::<MatchOnly>(self.haystack, self.needle, false)
+                }
+            }
+        }
+    }
+
+    /// Finds the next match from the back, as a range of indices.
+    #[inline]
+    pub(crate) fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        match self.searcher {
+            BytesSearcherImpl::Empty { position, ref mut end, ref mut exhausted } => {
+                if *exhausted {
+                    return None;
+                }
+                let pos = *end;
+                if pos == position {
+                    *exhausted = true;
+                } else {
+                    *end -= 1;
+                }
+                Some((pos, pos))
+            }
+            BytesSearcherImpl::Byte(ref mut searcher) => searcher.next_match_back(self.haystack),
+            BytesSearcherImpl::TwoWay(ref mut searcher) => {
+                let is_long = searcher.memory == usize::MAX;
+                if is_long {
+                    searcher.next_back::<MatchOnly>(self.haystack, self.needle, true)
+                } else {
+                    searcher.next_back::<MatchOnly>(self.haystack, self.needle, false)
+                }
+            }
+        }
+    }
+}
+
 /// The internal state of the two-way substring search algorithm.
 #[derive(Clone, Debug)]
 struct TwoWaySearcher {
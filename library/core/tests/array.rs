@@ -290,3 +290,72 @@ fn empty_array_is_always_default() {
 
     let _arr = <[DoesNotImplDefault; 0]>::default();
 }
+
+#[test]
+fn array_map() {
+    let a = [1, 2, 3];
+    let b = a.map(|v| v + 1);
+    assert_eq!(b, [2, 3, 4]);
+
+    let a = [1u8, 2, 3];
+    let b = a.map(|v| v as u64);
+    assert_eq!(b, [1, 2, 3]);
+
+    let strings = ["a".to_string(), "bc".to_string()];
+    assert_eq!(strings.map(|s| s.len()), [1, 2]);
+}
+
+// See note on above test for why `should_panic` is used.
+#[test]
+#[should_panic(expected = "test succeeded")]
+fn array_map_drop_safety() {
+    use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+    struct DropCounter;
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Relaxed);
+        }
+    }
+
+    let num_to_create = 5;
+    let success = std::panic::catch_unwind(|| {
+        let items = [0; 10];
+        let mut nth = 0;
+        items.map(|_| {
+            assert!(nth < num_to_create);
+            nth += 1;
+            DropCounter
+        });
+    });
+    assert!(success.is_err());
+    assert_eq!(DROPPED.load(Relaxed), num_to_create);
+    panic!("test succeeded")
+}
+
+#[test]
+fn array_zip() {
+    let a = [1, 2, 3];
+    let b = ["a", "b", "c"];
+    assert_eq!(a.zip(b), [(1, "a"), (2, "b"), (3, "c")]);
+    assert_eq!([(); 0].zip([0u8; 0]), []);
+}
+
+#[test]
+fn array_try_from_fn() {
+    let array: Result<[usize; 5], ()> = core::array::try_from_fn(|i| Ok(i * i));
+    assert_eq!(array, Ok([0, 1, 4, 9, 16]));
+
+    use core::cell::Cell;
+    let dropped = Cell::new(0);
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+    let array: Result<[Counted<'_>; 5], usize> =
+        core::array::try_from_fn(|i| if i < 3 { Ok(Counted(&dropped)) } else { Err(i) });
+    assert_eq!(array.err(), Some(3));
+    assert_eq!(dropped.get(), 3);
+}
@@ -304,6 +304,51 @@ fn test_zip_nth_side_effects() {
     assert_eq!(b, vec![200, 300, 400, 500, 600]);
 }
 
+#[test]
+fn test_iterator_next_chunk() {
+    let mut it = 0..12;
+    assert_eq!(it.next_chunk().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(it.next_chunk().unwrap(), []);
+    assert_eq!(it.next_chunk().unwrap(), [4, 5, 6, 7, 8, 9]);
+    assert_eq!(it.next_chunk::<4>().unwrap_err().collect::<Vec<_>>(), [10, 11]);
+    assert_eq!(it.next_chunk::<2>().unwrap_err().count(), 0);
+}
+
+#[test]
+fn test_iterator_array_chunks() {
+    let mut it = (0..7).array_chunks::<3>();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    assert_eq!(it.next(), Some([0, 1, 2]));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next(), Some([3, 4, 5]));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.into_remainder().unwrap().collect::<Vec<_>>(), [6]);
+
+    let mut it = (0..6).array_chunks::<2>();
+    assert!(it.by_ref().eq([[0, 1], [2, 3], [4, 5]].iter().copied()));
+    assert_eq!(it.into_remainder().unwrap().len(), 0);
+
+    assert!((0..3).array_chunks::<2>().into_remainder().is_none());
+
+    // The underlying iterator is not polled again once it returned `None`.
+    let mut n = 0;
+    let mut it = from_fn(|| {
+        n += 1;
+        if n == 3 { None } else { Some(n) }
+    })
+    .array_chunks::<3>();
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.into_remainder().unwrap().collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_iterator_array_chunks_zero() {
+    let _ = (0..3).array_chunks::<0>();
+}
+
 #[test]
 fn test_iterator_step_by() {
     // Identity
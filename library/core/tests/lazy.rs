@@ -82,6 +82,24 @@ fn into_inner() {
     assert_eq!(cell.into_inner(), Some("hello"));
 }
 
+#[test]
+fn take() {
+    let mut cell = OnceCell::new();
+    assert_eq!(cell.take(), None);
+    cell.set(String::from("hello")).unwrap();
+    assert_eq!(cell.take().as_deref(), Some("hello"));
+    assert!(cell.get().is_none());
+}
+
+#[test]
+fn get_or_try_init() {
+    let cell: OnceCell<String> = OnceCell::new();
+    assert_eq!(cell.get_or_try_init(|| Err(())), Err(()));
+    assert!(cell.get().is_none());
+    assert_eq!(cell.get_or_try_init(|| Ok::<_, ()>("hello".to_string())).unwrap(), "hello");
+    assert_eq!(cell.get_or_try_init(|| Err(())), Ok(&"hello".to_string()));
+}
+
 #[test]
 fn lazy_new() {
     let called = Cell::new(0);
@@ -122,3 +140,12 @@ fn reentrant_init() {
     });
     eprintln!("use after free: {:?}", dangling_ref.get().unwrap());
 }
+
+#[test]
+#[should_panic(expected = "`Lazy` instance has previously been poisoned")]
+fn lazy_poisoned() {
+    let x: Lazy<i32> = Lazy::new(|| panic!("kaboom"));
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| *x));
+    assert!(res.is_err());
+    let _ = *x;
+}
@@ -18,7 +18,7 @@
 #![feature(range_is_empty)]
 #![feature(raw)]
 #![feature(sort_internals)]
-#![feature(slice_partition_at_index)]
+#![feature(slice_select_nth_unstable)]
 #![feature(min_specialization)]
 #![feature(step_trait)]
 #![feature(step_trait_ext)]
@@ -44,6 +44,22 @@
 #![feature(peekable_next_if)]
 #![feature(partition_point)]
 #![feature(once_cell)]
+#![feature(portable_simd)]
+#![feature(array_chunks)]
+#![feature(array_map)]
+#![feature(array_try_from_fn)]
+#![feature(array_windows)]
+#![feature(array_zip)]
+#![feature(iter_array_chunks)]
+#![feature(iter_next_chunk)]
+#![feature(slice_as_chunks)]
+#![feature(slice_group_by)]
+#![feature(str_multi_pattern)]
+#![feature(byte_slice_pattern)]
+#![feature(byte_slice_lines)]
+#![feature(byte_slice_trim_ascii)]
+#![feature(inherent_ascii_escape)]
+#![feature(strict_provenance)]
 #![feature(unsafe_block_in_unsafe_fn)]
 #![deny(unsafe_op_in_unsafe_fn)]
 
@@ -73,6 +89,7 @@ mod option;
 mod pattern;
 mod ptr;
 mod result;
+mod simd;
 mod slice;
 mod str;
 mod str_lossy;
@@ -501,3 +501,81 @@ fn double_ended_regression_test() {
         [InRange(10, 13), Rejects(13, 14), InRange(37, 40), Rejects(34, 37), Done]
     );
 }
+
+#[test]
+fn test_single_byte_str_search() {
+    search_asserts!(
+        "abcdeab",
+        "a",
+        "forward iteration for a one byte needle",
+        [next, next, next, next, next],
+        [Matches(0, 1), Rejects(1, 5), Matches(5, 6), Rejects(6, 7), Done]
+    );
+    search_asserts!(
+        "abcdeab",
+        "a",
+        "reverse iteration for a one byte needle",
+        [next_back, next_back, next_back, next_back, next_back],
+        [Rejects(6, 7), Matches(5, 6), Rejects(1, 5), Matches(0, 1), Done]
+    );
+    search_asserts!(
+        "abcdeabcdeabcde",
+        "d",
+        "double ended search for a one byte needle",
+        [next_match, next_match_back, next_match_back, next_match_back],
+        [InRange(3, 4), InRange(13, 14), InRange(8, 9), Done]
+    );
+}
+
+#[test]
+fn test_multi_str_search() {
+    search_asserts!(
+        "abcd",
+        ["cd", "bc"],
+        "forward iteration for several needles",
+        [next, next, next, next],
+        [Rejects(0, 1), Matches(1, 3), Rejects(3, 4), Done]
+    );
+    search_asserts!(
+        STRESS,
+        ["각", "😁"],
+        "forward search for several needles",
+        [next_match, next_match, next_match, next, next_match, next_match, next_match],
+        [
+            InRange(15, 19),
+            InRange(19, 22),
+            InRange(28, 31),
+            Rejects(31, 32),
+            InRange(34, 37),
+            InRange(43, 47),
+            Done
+        ]
+    );
+    search_asserts!(
+        STRESS,
+        ["ꁁ", "Á"],
+        "reverse search for needles sharing their last byte",
+        [next_match_back, next_match_back, next_match_back, next_back, next_match_back],
+        [InRange(37, 40), InRange(32, 34), InRange(10, 13), Matches(8, 10), InRange(0, 2)]
+    );
+    search_asserts!(
+        "ab",
+        ["x", ""],
+        "forward iteration with an empty needle",
+        [next, next, next, next, next, next],
+        [Matches(0, 0), Rejects(0, 1), Matches(1, 1), Rejects(1, 2), Matches(2, 2), Done]
+    );
+}
+
+#[test]
+fn test_multi_str_pattern_methods() {
+    assert_eq!("category".matches(["category", "cat"]).collect::<Vec<_>>(), ["category"]);
+    assert_eq!("category".matches(["cat", "category"]).collect::<Vec<_>>(), ["cat"]);
+    assert_eq!("a, b;c".split([", ", ";"]).collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!("a, b;c".rsplit([", ", ";"]).collect::<Vec<_>>(), ["c", "b", "a"]);
+    assert_eq!("foo.tar.gz".strip_suffix([".zip", ".gz"]), Some("foo.tar"));
+    assert!("foo.tar.gz".starts_with(["bar", "foo"]));
+    assert!(!"foo.tar.gz".ends_with(["bar", "foo"]));
+    assert_eq!("foo".find(["bar", "baz"]), None);
+    assert_eq!("foo".find([""; 0]), None);
+}
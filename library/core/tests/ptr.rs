@@ -401,3 +401,40 @@ fn align_offset_weird_strides() {
     }
     assert!(!x);
 }
+
+#[test]
+fn test_strict_provenance() {
+    let mut x = [1u32, 2, 3, 4];
+    let p = x.as_mut_ptr();
+    assert_eq!(p.addr(), p as usize);
+    assert_eq!((p as *const u32).addr(), p.addr());
+
+    // tag the low bits of an aligned pointer and strip them again
+    let tagged = p.wrapping_add(2).map_addr(|addr| addr | 0b11);
+    assert_eq!(tagged.addr() & 0b11, 0b11);
+    assert!(!tagged.is_aligned_to(2));
+    let untagged = tagged.map_addr(|addr| addr & !0b11);
+    assert!(untagged.is_aligned_to(4));
+    unsafe {
+        *untagged = 7;
+    }
+    assert_eq!(x, [1, 2, 7, 4]);
+
+    let q = x.as_ptr();
+    assert_eq!(unsafe { *q.with_addr(q.addr() + 4) }, 2);
+    assert_eq!(unsafe { *from_exposed_addr::<u32>(q.expose_addr()) }, 1);
+
+    let slice: *const [u32] = &x[1..];
+    assert_eq!(slice.addr(), q.addr() + 4);
+
+    let sentinel: *const u64 = invalid(8);
+    assert_eq!(sentinel.addr(), 8);
+    assert!(sentinel.is_aligned_to(8));
+    assert!(!invalid_mut::<u8>(usize::MAX).is_null());
+}
+
+#[test]
+#[should_panic]
+fn test_is_aligned_to_non_power_of_two() {
+    invalid::<u8>(8).is_aligned_to(3);
+}
@@ -0,0 +1,141 @@
+use core::simd::{Mask, Shuffle, Simd};
+
+#[test]
+fn array_roundtrip() {
+    let a = Simd::from_array([1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    assert_eq!(a.to_array(), [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    assert_eq!(a[3], 4);
+    assert_eq!(a.lanes(), 16);
+
+    let mut b = Simd::<i64, 2>::splat(7);
+    b[1] = 9;
+    assert_eq!(b.to_array(), [7, 9]);
+    assert_eq!(Simd::<i64, 2>::from_slice(&[1, 2, 3]).to_array(), [1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn from_slice_too_short() {
+    Simd::<u32, 4>::from_slice(&[1, 2, 3]);
+}
+
+#[test]
+fn int_arithmetic() {
+    let a = Simd::from_array([1i32, -2, 3, i32::MAX]);
+    let b = Simd::from_array([4i32, 5, -6, 1]);
+    assert_eq!((a + b).to_array(), [5, 3, -3, i32::MIN]);
+    assert_eq!((a - b).to_array(), [-3, -7, 9, i32::MAX - 1]);
+    assert_eq!((a * b).to_array(), [4, -10, -18, i32::MAX]);
+    assert_eq!((b / a).to_array(), [4, -2, -2, 0]);
+    assert_eq!((b % a).to_array(), [0, 1, 0, 1]);
+    assert_eq!((-a).to_array(), [-1, 2, -3, -i32::MAX]);
+    assert_eq!((!a).to_array(), [!1, !-2, !3, !i32::MAX]);
+    assert_eq!((a & b).to_array(), [1 & 4, -2 & 5, 3 & -6, i32::MAX & 1]);
+
+    let mut c = Simd::from_array([1u16, 2, 3, 4]);
+    c <<= Simd::from_array([0, 1, 2, 15]);
+    assert_eq!(c.to_array(), [1, 4, 12, 0]);
+    c >>= Simd::splat(1);
+    assert_eq!(c.to_array(), [0, 2, 6, 0]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn int_div_by_zero() {
+    let _ = Simd::from_array([1u8, 2, 3, 4]) / Simd::from_array([1, 1, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn int_div_overflow() {
+    let _ = Simd::from_array([i8::MIN, 2]) / Simd::from_array([-1, 1]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn int_shl_overflow() {
+    let _ = Simd::from_array([1u32, 2]) << Simd::from_array([1, 32]);
+}
+
+#[test]
+fn float_arithmetic() {
+    let a = Simd::from_array([1.0f64, -2.5, 0.0, 9.0]);
+    let b = Simd::splat(2.0);
+    assert_eq!((a + b).to_array(), [3.0, -0.5, 2.0, 11.0]);
+    assert_eq!((a / b).to_array(), [0.5, -1.25, 0.0, 4.5]);
+    assert_eq!(a.abs().to_array(), [1.0, 2.5, 0.0, 9.0]);
+    assert_eq!(a.abs().sqrt().to_array()[3], 3.0);
+    assert!((-a).to_array()[2].is_sign_negative());
+    assert_eq!(a.min(b).to_array(), [1.0, -2.5, 0.0, 2.0]);
+    assert_eq!(a.max(Simd::splat(f64::NAN)).to_array(), a.to_array());
+}
+
+#[test]
+fn comparisons_and_masks() {
+    let a = Simd::from_array([1.0f32, 2.0, f32::NAN, 4.0]);
+    let b = Simd::from_array([1.0f32, 3.0, 3.0, 0.0]);
+    assert_eq!(a.lanes_eq(b).to_array(), [true, false, false, false]);
+    assert_eq!(a.lanes_ne(b).to_array(), [false, true, true, true]);
+    assert_eq!(a.lanes_lt(b).to_array(), [false, true, false, false]);
+    assert_eq!(a.lanes_ge(b).to_array(), [true, false, false, true]);
+    assert_ne!(a, a);
+
+    let mut mask = a.lanes_le(b);
+    assert!(mask.any());
+    assert!(!mask.all());
+    assert!(mask.test(1));
+    mask.set(3, true);
+    assert_eq!(mask, Mask::from_array([true, true, false, true]));
+    assert_eq!((!mask).to_array(), [false, false, true, false]);
+    assert!((mask | !mask).all());
+    assert!(!(mask & !mask).any());
+    assert_eq!(mask.select(a, b).to_array(), [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn reductions() {
+    let a = Simd::from_array([3i16, -1, 4, 1, -5, 9, 2, 6]);
+    assert_eq!(a.horizontal_sum(), 19);
+    assert_eq!(a.horizontal_product(), 6480);
+    assert_eq!(a.horizontal_min(), -5);
+    assert_eq!(a.horizontal_max(), 9);
+    assert_eq!(a.horizontal_and(), 3 & -1 & 4 & 1 & -5 & 9 & 2 & 6);
+    assert_eq!(a.horizontal_or(), 3 | -1 | 4 | 1 | -5 | 9 | 2 | 6);
+    assert_eq!(a.horizontal_xor(), 3 ^ -1 ^ 4 ^ 1 ^ -5 ^ 9 ^ 2 ^ 6);
+
+    assert_eq!(Simd::<u8, 32>::splat(u8::MAX).horizontal_sum(), u8::MAX.wrapping_mul(32));
+
+    let f = Simd::from_array([0.5f32, 1.5, f32::NAN, -2.0]);
+    assert_eq!(f.horizontal_min(), -2.0);
+    assert_eq!(f.horizontal_max(), 1.5);
+    assert_eq!(Simd::from_array([0.5f32, 1.5, 4.0, -2.0]).horizontal_product(), -6.0);
+}
+
+#[test]
+fn shuffle() {
+    struct Reverse;
+    impl Shuffle<4> for Reverse {
+        const INDEX: [u32; 4] = [3, 2, 1, 0];
+    }
+    struct Concat;
+    impl Shuffle<4> for Concat {
+        const INDEX: [u32; 4] = [2, 3, 4, 5];
+    }
+
+    let a = Simd::from_array([1u64, 2, 3, 4]);
+    let b = Simd::from_array([5u64, 6, 7, 8]);
+    assert_eq!(a.shuffle::<Reverse>(b).to_array(), [4, 3, 2, 1]);
+    assert_eq!(a.shuffle::<Concat>(b).to_array(), [3, 4, 5, 6]);
+}
+
+#[test]
+fn gather() {
+    let values = [10.0f32, 11.0, 12.0, 13.0, 14.0];
+    let idxs = Simd::from_array([4, 0, 7, 2]);
+    assert_eq!(Simd::gather_or_default(&values, idxs).to_array(), [14.0, 10.0, 0.0, 12.0]);
+
+    let enable = Mask::from_array([true, false, true, true]);
+    let or = Simd::splat(-1.0);
+    let gathered = Simd::gather_select(&values, enable, idxs, or);
+    assert_eq!(gathered.to_array(), [14.0, -1.0, -1.0, 12.0]);
+}
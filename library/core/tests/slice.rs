@@ -394,6 +394,135 @@ fn test_chunks_exact_zip() {
     assert_eq!(res, vec![14, 22]);
 }
 
+#[test]
+fn test_as_chunks() {
+    let v = [1, 2, 3, 4, 5];
+    let (chunks, rem) = v.as_chunks::<2>();
+    assert_eq!(chunks, [[1, 2], [3, 4]]);
+    assert_eq!(rem, [5]);
+
+    let (chunks, rem) = v.as_chunks::<6>();
+    assert!(chunks.is_empty());
+    assert_eq!(rem, v);
+
+    let mut v = [0u8; 7];
+    let (chunks, rem) = v.as_chunks_mut::<3>();
+    chunks[1] = [1, 2, 3];
+    rem[0] = 9;
+    assert_eq!(v, [0, 0, 0, 1, 2, 3, 9]);
+}
+
+#[test]
+fn test_array_chunks() {
+    let v = [0, 1, 2, 3, 4, 5, 6];
+    let mut c = v.array_chunks::<3>();
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.remainder(), [6]);
+    assert_eq!(c.next_back(), Some(&[3, 4, 5]));
+    assert_eq!(c.next(), Some(&[0, 1, 2]));
+    assert_eq!(c.next(), None);
+
+    let mut c = v.array_chunks::<2>();
+    assert_eq!(c.nth(1), Some(&[2, 3]));
+    assert_eq!(c.nth_back(1), None);
+
+    let v: [i32; 0] = [];
+    assert_eq!(v.array_chunks::<2>().count(), 0);
+
+    let v = [1, 2, 3, 4, 5, 6];
+    let c = v.array_chunks::<2>();
+    let sums: Vec<_> = c.zip(v.array_chunks::<2>().rev()).map(|(a, b)| a[0] + b[1]).collect();
+    assert_eq!(sums, [7, 7, 7]);
+}
+
+#[test]
+#[should_panic]
+fn test_array_chunks_zero() {
+    let _ = [1, 2, 3].array_chunks::<0>();
+}
+
+#[test]
+fn test_array_windows() {
+    let v = [0, 1, 2, 3, 4];
+    let mut w = v.array_windows::<3>();
+    assert_eq!(w.len(), 3);
+    assert_eq!(w.next(), Some(&[0, 1, 2]));
+    assert_eq!(w.next_back(), Some(&[2, 3, 4]));
+    assert_eq!(w.len(), 1);
+    assert_eq!(w.next(), Some(&[1, 2, 3]));
+    assert_eq!(w.next(), None);
+    assert_eq!(w.next_back(), None);
+
+    let mut w = v.array_windows::<2>();
+    assert_eq!(w.nth(2), Some(&[2, 3]));
+    assert_eq!(w.nth_back(0), Some(&[3, 4]));
+    assert_eq!(w.nth(0), None);
+
+    assert_eq!(v.array_windows::<6>().count(), 0);
+    assert_eq!(v.array_windows::<1>().last(), Some(&[4]));
+    let sums: Vec<i32> = v.array_windows::<2>().map(|[a, b]| a + b).collect();
+    assert_eq!(sums, [1, 3, 5, 7]);
+}
+
+#[test]
+#[should_panic]
+fn test_array_windows_zero() {
+    let _ = [1, 2, 3].array_windows::<0>();
+}
+
+#[test]
+fn test_group_by() {
+    let slice = &[1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
+
+    let mut iter = slice.group_by(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next(), Some(&[3, 3][..]));
+    assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next(), Some(&[1][..]));
+    assert_eq!(iter.next(), Some(&[0][..]));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = slice.group_by(|a, b| a == b);
+    assert_eq!(iter.next_back(), Some(&[0][..]));
+    assert_eq!(iter.next_back(), Some(&[1][..]));
+    assert_eq!(iter.next_back(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next_back(), Some(&[3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = slice.group_by(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next_back(), Some(&[0][..]));
+    assert_eq!(iter.next(), Some(&[3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&[1][..]));
+    assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next_back(), None);
+
+    let empty: &[i32] = &[];
+    assert_eq!(empty.group_by(|a, b| a == b).size_hint(), (0, Some(0)));
+    assert_eq!(slice.group_by(|a, b| a <= b).count(), 4);
+    assert_eq!(slice.group_by(|a, b| a <= b).last(), Some(&[0][..]));
+}
+
+#[test]
+fn test_group_by_mut() {
+    let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
+
+    let mut iter = slice.group_by_mut(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
+    assert_eq!(iter.next_back(), Some(&mut [0][..]));
+    assert_eq!(iter.next(), Some(&mut [3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&mut [1][..]));
+    assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
+    assert_eq!(iter.next_back(), None);
+
+    for run in slice.group_by_mut(|a, b| a == b) {
+        run.reverse();
+        run[0] = run.len() as i32;
+    }
+    assert_eq!(slice, &[3, 1, 1, 2, 3, 3, 2, 2, 1, 1]);
+}
+
 #[test]
 fn test_chunks_exact_mut_count() {
     let v: &mut [i32] = &mut [0, 1, 2, 3, 4, 5];
@@ -1337,7 +1466,7 @@ fn sort_unstable() {
 #[test]
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg_attr(miri, ignore)] // Miri is too slow
-fn partition_at_index() {
+fn select_nth_unstable() {
     use core::cmp::Ordering::{Equal, Greater, Less};
     use rand::rngs::StdRng;
     use rand::seq::SliceRandom;
@@ -1363,7 +1492,7 @@ fn partition_at_index() {
                 // Sort in default order.
                 for pivot in 0..len {
                     let mut v = orig.clone();
-                    v.partition_at_index(pivot);
+                    v.select_nth_unstable(pivot);
 
                     assert_eq!(v_sorted[pivot], v[pivot]);
                     for i in 0..pivot {
@@ -1376,7 +1505,7 @@ fn partition_at_index() {
                 // Sort in ascending order.
                 for pivot in 0..len {
                     let mut v = orig.clone();
-                    let (left, pivot, right) = v.partition_at_index_by(pivot, |a, b| a.cmp(b));
+                    let (left, pivot, right) = v.select_nth_unstable_by(pivot, |a, b| a.cmp(b));
 
                     assert_eq!(left.len() + right.len(), len - 1);
 
@@ -1399,7 +1528,7 @@ fn partition_at_index() {
 
                 for pivot in 0..len {
                     let mut v = orig.clone();
-                    v.partition_at_index_by(pivot, sort_descending_comparator);
+                    v.select_nth_unstable_by(pivot, sort_descending_comparator);
 
                     assert_eq!(v_sorted_descending[pivot], v[pivot]);
                     for i in 0..pivot {
@@ -1420,7 +1549,7 @@ fn partition_at_index() {
     }
 
     for pivot in 0..v.len() {
-        v.partition_at_index_by(pivot, |_, _| *[Less, Equal, Greater].choose(&mut rng).unwrap());
+        v.select_nth_unstable_by(pivot, |_, _| *[Less, Equal, Greater].choose(&mut rng).unwrap());
         v.sort();
         for i in 0..v.len() {
             assert_eq!(v[i], i as i32);
@@ -1428,28 +1557,28 @@ fn partition_at_index() {
     }
 
     // Should not panic.
-    [(); 10].partition_at_index(0);
-    [(); 10].partition_at_index(5);
-    [(); 10].partition_at_index(9);
-    [(); 100].partition_at_index(0);
-    [(); 100].partition_at_index(50);
-    [(); 100].partition_at_index(99);
+    [(); 10].select_nth_unstable(0);
+    [(); 10].select_nth_unstable(5);
+    [(); 10].select_nth_unstable(9);
+    [(); 100].select_nth_unstable(0);
+    [(); 100].select_nth_unstable(50);
+    [(); 100].select_nth_unstable(99);
 
     let mut v = [0xDEADBEEFu64];
-    v.partition_at_index(0);
+    v.select_nth_unstable(0);
     assert!(v == [0xDEADBEEF]);
 }
 
 #[test]
 #[should_panic(expected = "index 0 greater than length of slice")]
-fn partition_at_index_zero_length() {
-    [0i32; 0].partition_at_index(0);
+fn select_nth_unstable_zero_length() {
+    [0i32; 0].select_nth_unstable(0);
 }
 
 #[test]
 #[should_panic(expected = "index 20 greater than length of slice")]
-fn partition_at_index_past_length() {
-    [0i32; 10].partition_at_index(20);
+fn select_nth_unstable_past_length() {
+    [0i32; 10].select_nth_unstable(20);
 }
 
 pub mod memchr {
@@ -1749,3 +1878,80 @@ fn test_is_sorted() {
     assert!(!["c", "bb", "aaa"].is_sorted());
     assert!(["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
 }
+
+#[test]
+fn test_byte_find() {
+    let haystack = b"abcabcabcd";
+    assert_eq!(haystack.find(b"abcd"), Some(6));
+    assert_eq!(haystack.find(b"c"), Some(2));
+    assert_eq!(haystack.rfind(b"c"), Some(8));
+    assert_eq!(haystack.rfind(b"abc"), Some(6));
+    assert_eq!(haystack.find(b"abd"), None);
+    assert_eq!(haystack.rfind(b"x"), None);
+    assert_eq!(haystack.find(b""), Some(0));
+    assert_eq!(haystack.rfind(b""), Some(10));
+    assert_eq!(b"".find(b"a"), None);
+
+    // matches don't have to be on UTF-8 boundaries
+    assert_eq!(b"\xe2\x82\xac\x82".find(b"\x82"), Some(1));
+    assert_eq!(b"\xe2\x82\xac\x82".rfind(b"\x82"), Some(3));
+}
+
+#[test]
+fn test_split_pattern() {
+    let split = |v: &[u8], needle: &[u8]| v.split_pattern(needle).collect::<Vec<_>>();
+    assert_eq!(split(b"a--b--c", b"--"), [&b"a"[..], b"b", b"c"]);
+    assert_eq!(split(b"--a---", b"--"), [&b""[..], b"a", b"-"]);
+    assert_eq!(split(b"a-b", b"-"), [&b"a"[..], b"b"]);
+    assert_eq!(split(b"ab", b""), [&b""[..], b"a", b"b", b""]);
+    assert_eq!(split(b"", b"-"), [&b""[..]]);
+
+    let mut iter = b"a-b".split_pattern(b"-");
+    assert_eq!(iter.size_hint(), (1, Some(5)));
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_byte_lines() {
+    let lines = |v: &[u8]| v.byte_lines().collect::<Vec<_>>();
+    assert_eq!(lines(b"a\nb\r\n\nc\r"), [&b"a"[..], b"b", b"", b"c"]);
+    assert_eq!(lines(b"a\n"), [&b"a"[..]]);
+    assert_eq!(lines(b"\n"), [&b""[..]]);
+    assert!(lines(b"").is_empty());
+
+    let rev = |v: &[u8]| v.byte_lines().rev().collect::<Vec<_>>();
+    assert_eq!(rev(b"a\nb\r\n\nc\r"), [&b"c"[..], b"", b"b", b"a"]);
+    assert_eq!(rev(b"a\n\n"), [&b""[..], b"a"]);
+
+    let mut iter = b"a\nb\nc".byte_lines();
+    assert_eq!(iter.next(), Some(&b"a"[..]));
+    assert_eq!(iter.next_back(), Some(&b"c"[..]));
+    assert_eq!(iter.next(), Some(&b"b"[..]));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_trim_ascii() {
+    assert_eq!(b" \t\x0c\r\nfoo bar\n ".trim_ascii(), b"foo bar");
+    assert_eq!(b" foo ".trim_ascii_start(), b"foo ");
+    assert_eq!(b" foo ".trim_ascii_end(), b" foo");
+    // vertical tab isn't ASCII whitespace
+    assert_eq!(b"\x0bfoo\x0b".trim_ascii(), b"\x0bfoo\x0b");
+    assert_eq!(b"\xa0foo".trim_ascii(), b"\xa0foo");
+    assert_eq!(b" \n ".trim_ascii(), b"");
+}
+
+#[test]
+fn test_escape_ascii() {
+    assert_eq!(b"".escape_ascii().to_string(), "");
+    assert_eq!(b"abc".escape_ascii().to_string(), "abc");
+    assert_eq!(b"\x00\x7f\xff\t".escape_ascii().to_string(), "\\x00\\x7f\\xff\\t");
+
+    let mut iter = b"\n\x01".escape_ascii();
+    assert_eq!(iter.size_hint(), (0, None));
+    assert_eq!(iter.next(), Some(b'\\'));
+    assert_eq!(iter.next_back(), Some(b'1'));
+    assert_eq!(iter.collect::<Vec<_>>(), b"n\\x0");
+}
@@ -83,20 +83,24 @@
 // of an address to a human readable name for as long as possible. When
 // `Backtrace::create` is called to capture a backtrace it doesn't actually
 // perform any symbol resolution, but rather we lazily resolve symbols only just
-// before they're needed for printing. This way we can make capturing a
-// backtrace and throwing it away much cheaper, but actually printing a
-// backtrace is still basically the same cost.
+// before they're needed for printing or inspected through `frames`. Each frame
+// is resolved on its own, so looking at the first few frames doesn't pay for
+// the rest of the stack. This way we can make capturing a backtrace and
+// throwing it away much cheaper, but actually printing a backtrace is still
+// basically the same cost.
 //
 // This strategy comes at the cost of some synchronization required inside of a
-// `Backtrace`, but that's a relatively small price to pay relative to capturing
-// a backtrace or actually symbolizing it.
+// `Backtrace` (a `SyncOnceCell` per frame), but that's a relatively small price
+// to pay relative to capturing a backtrace or actually symbolizing it.
 
+use crate::borrow::Cow;
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
+use crate::lazy::SyncOnceCell;
+use crate::path::Path;
 use crate::sync::atomic::{AtomicUsize, Ordering::SeqCst};
-use crate::sync::Mutex;
-use crate::sys_common::backtrace::{lock, output_filename};
+use crate::sys_common::backtrace::{filename_to_path, lock, output_filename};
 use crate::vec::Vec;
 use backtrace::BytesOrWideString;
 use backtrace_rs as backtrace;
@@ -130,12 +134,11 @@ pub enum BacktraceStatus {
 enum Inner {
     Unsupported,
     Disabled,
-    Captured(Mutex<Capture>),
+    Captured(Capture),
 }
 
 struct Capture {
     actual_start: usize,
-    resolved: bool,
     frames: Vec<BacktraceFrame>,
 }
 
@@ -144,9 +147,15 @@ fn _assert_send_sync() {
     _assert::<Backtrace>();
 }
 
-struct BacktraceFrame {
+/// A single frame of a captured backtrace.
+///
+/// Frames are returned by `Backtrace::frames`. The instruction pointer of a
+/// frame is known as soon as the backtrace is captured, but its symbols are
+/// only resolved the first time `BacktraceFrame::symbols` is called.
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct BacktraceFrame {
     frame: RawFrame,
-    symbols: Vec<BacktraceSymbol>,
+    symbols: SyncOnceCell<Vec<BacktraceSymbol>>,
 }
 
 enum RawFrame {
@@ -155,7 +164,12 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol resolved for a frame of a captured backtrace.
+///
+/// A single frame may resolve to several symbols when functions have been
+/// inlined into each other, in which case the innermost function comes first.
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -166,33 +180,52 @@ enum BytesOrWide {
     Wide(Vec<u16>),
 }
 
+/// Helper struct for writing the unresolved instruction pointers of a
+/// backtrace, created by `Backtrace::frame_addresses`.
+#[unstable(feature = "backtrace_frames", issue = "none")]
+pub struct FrameAddresses<'a> {
+    frames: &'a [BacktraceFrame],
+}
+
 impl fmt::Debug for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut capture = match &self.inner {
+        match &self.inner {
             Inner::Unsupported => return fmt.write_str("<unsupported>"),
             Inner::Disabled => return fmt.write_str("<disabled>"),
-            Inner::Captured(c) => c.lock().unwrap(),
-        };
-        capture.resolve();
-
-        let frames = &capture.frames[capture.actual_start..];
+            Inner::Captured(_) => {}
+        }
 
         write!(fmt, "Backtrace ")?;
 
         let mut dbg = fmt.debug_list();
 
-        for frame in frames {
-            if frame.frame.ip().is_null() {
+        for frame in self.frames() {
+            if frame.ip().is_null() {
                 continue;
             }
 
-            dbg.entries(&frame.symbols);
+            dbg.entries(frame.symbols());
         }
 
         dbg.finish()
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "none")]
+impl fmt::Debug for BacktraceFrame {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut dbg = fmt.debug_struct("BacktraceFrame");
+        dbg.field("ip", &self.ip());
+        // Only show symbols that have already been resolved, formatting a
+        // frame shouldn't be what triggers the expensive resolution.
+        if let Some(symbols) = self.symbols.get() {
+            dbg.field("symbols", symbols);
+        }
+        dbg.finish()
+    }
+}
+
+#[unstable(feature = "backtrace_frames", issue = "none")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(fmt, "{{ ")?;
@@ -219,16 +252,30 @@ impl fmt::Debug for BytesOrWide {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         output_filename(
             fmt,
-            match self {
-                BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-            },
+            self.as_bows(),
             backtrace::PrintFmt::Short,
             crate::env::current_dir().as_ref().ok(),
         )
     }
 }
 
+#[unstable(feature = "backtrace_frames", issue = "none")]
+impl fmt::Debug for FrameAddresses<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list().entries(self.frames.iter().map(|frame| frame.ip())).finish()
+    }
+}
+
+#[unstable(feature = "backtrace_frames", issue = "none")]
+impl fmt::Display for FrameAddresses<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for frame in self.frames {
+            writeln!(fmt, "{:p}", frame.ip())?;
+        }
+        Ok(())
+    }
+}
+
 impl Backtrace {
     /// Returns whether backtrace captures are enabled through environment
     /// variables.
@@ -308,7 +355,7 @@ impl Backtrace {
             backtrace::trace_unsynchronized(|frame| {
                 frames.push(BacktraceFrame {
                     frame: RawFrame::Actual(frame.clone()),
-                    symbols: Vec::new(),
+                    symbols: SyncOnceCell::new(),
                 });
                 if frame.symbol_address() as usize == ip && actual_start.is_none() {
                     actual_start = Some(frames.len());
@@ -323,11 +370,7 @@ impl Backtrace {
         let inner = if frames.is_empty() {
             Inner::Unsupported
         } else {
-            Inner::Captured(Mutex::new(Capture {
-                actual_start: actual_start.unwrap_or(0),
-                frames,
-                resolved: false,
-            }))
+            Inner::Captured(Capture { actual_start: actual_start.unwrap_or(0), frames })
         };
 
         Backtrace { inner }
@@ -343,16 +386,71 @@ impl Backtrace {
             Inner::Captured(_) => BacktraceStatus::Captured,
         }
     }
+
+    /// Returns the frames of this backtrace, starting with the caller of the
+    /// function that captured it.
+    ///
+    /// The returned slice is empty if no backtrace was captured. Calling this
+    /// function doesn't resolve any symbols, so it stays cheap; symbols are
+    /// resolved lazily, one frame at a time, by `BacktraceFrame::symbols`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    ///
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// for frame in backtrace.frames() {
+    ///     for symbol in frame.symbols() {
+    ///         if let (Some(name), Some(line)) = (symbol.name(), symbol.lineno()) {
+    ///             println!("{:?} at line {}", name, line);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn frames(&self) -> &[BacktraceFrame] {
+        match &self.inner {
+            Inner::Captured(c) => &c.frames[c.actual_start..],
+            Inner::Unsupported | Inner::Disabled => &[],
+        }
+    }
+
+    /// Returns an object implementing `Display` which writes the instruction
+    /// pointer of every frame of this backtrace, one per line, without
+    /// resolving any symbols.
+    ///
+    /// This is meant for serializing a backtrace to be symbolicated offline,
+    /// for example by a crash reporter. The addresses are those of the running
+    /// process, so for position independent executables and shared libraries
+    /// they need to be paired with the load addresses of the loaded modules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace, backtrace_frames)]
+    ///
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let backtrace = Backtrace::force_capture();
+    /// let report = backtrace.frame_addresses().to_string();
+    /// assert_eq!(report.lines().count(), backtrace.frames().len());
+    /// ```
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn frame_addresses(&self) -> FrameAddresses<'_> {
+        FrameAddresses { frames: self.frames() }
+    }
 }
 
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut capture = match &self.inner {
+        let capture = match &self.inner {
             Inner::Unsupported => return fmt.write_str("unsupported backtrace"),
             Inner::Disabled => return fmt.write_str("disabled backtrace"),
-            Inner::Captured(c) => c.lock().unwrap(),
+            Inner::Captured(c) => c,
         };
-        capture.resolve();
 
         let full = fmt.alternate();
         let (frames, style) = if full {
@@ -374,17 +472,15 @@ impl fmt::Display for Backtrace {
         f.add_context()?;
         for frame in frames {
             let mut f = f.frame();
-            if frame.symbols.is_empty() {
-                f.print_raw(frame.frame.ip(), None, None, None)?;
+            let symbols = frame.symbols();
+            if symbols.is_empty() {
+                f.print_raw(frame.ip(), None, None, None)?;
             } else {
-                for symbol in frame.symbols.iter() {
+                for symbol in symbols {
                     f.print_raw(
-                        frame.frame.ip(),
+                        frame.ip(),
                         symbol.name.as_ref().map(|b| backtrace::SymbolName::new(b)),
-                        symbol.filename.as_ref().map(|b| match b {
-                            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
-                            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
-                        }),
+                        symbol.filename.as_ref().map(|b| b.as_bows()),
                         symbol.lineno,
                     )?;
                 }
@@ -395,37 +491,75 @@ impl fmt::Display for Backtrace {
     }
 }
 
-impl Capture {
-    fn resolve(&mut self) {
-        // If we're already resolved, nothing to do!
-        if self.resolved {
-            return;
-        }
-        self.resolved = true;
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is normally the next instruction to execute in the frame, so it
+    /// usually points just past the call instruction. It is available without
+    /// resolving any symbols.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
 
-        // Use the global backtrace lock to synchronize this as it's a
-        // requirement of the `backtrace` crate, and then actually resolve
-        // everything.
-        let _lock = lock();
-        for frame in self.frames.iter_mut() {
-            let symbols = &mut frame.symbols;
-            let frame = match &frame.frame {
-                RawFrame::Actual(frame) => frame,
-                #[cfg(test)]
-                RawFrame::Fake => unimplemented!(),
-            };
-            unsafe {
-                backtrace::resolve_frame_unsynchronized(frame, |symbol| {
-                    symbols.push(BacktraceSymbol {
-                        name: symbol.name().map(|m| m.as_bytes().to_vec()),
-                        filename: symbol.filename_raw().map(|b| match b {
-                            BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
-                            BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
-                        }),
-                        lineno: symbol.lineno(),
-                    });
-                });
-            }
+    /// Returns the starting address of the function this frame belongs to,
+    /// if the platform's unwinder is able to determine it.
+    ///
+    /// Where it isn't, this is the same as `ip`.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the symbols of this frame, resolving them if that hasn't
+    /// happened yet.
+    ///
+    /// Resolving symbols can be slow since it may need to consult debug
+    /// information, but it only happens once per frame. The returned slice is
+    /// empty if no symbol information could be found.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        self.symbols.get_or_init(|| self.frame.resolve())
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, if it is known.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace::SymbolName::new(b)))
+    }
+
+    /// Returns the name of this symbol as it was found in the binary, before
+    /// demangling, if it is known.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn name_bytes(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the path of the source file this symbol was defined in, if it
+    /// is known.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn filename(&self) -> Option<Cow<'_, Path>> {
+        self.filename.as_ref().map(|b| filename_to_path(b.as_bows()))
+    }
+
+    /// Returns the line number in `filename` this symbol is at, if it is
+    /// known.
+    ///
+    /// Column numbers are not available: the symbolizer currently used by
+    /// the standard library doesn't report them.
+    #[unstable(feature = "backtrace_frames", issue = "none")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+}
+
+impl BytesOrWide {
+    fn as_bows(&self) -> BytesOrWideString<'_> {
+        match self {
+            BytesOrWide::Bytes(w) => BytesOrWideString::Bytes(w),
+            BytesOrWide::Wide(w) => BytesOrWideString::Wide(w),
         }
     }
 }
@@ -438,34 +572,68 @@ impl RawFrame {
             RawFrame::Fake => 1 as *mut c_void,
         }
     }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            #[cfg(test)]
+            RawFrame::Fake => 1 as *mut c_void,
+        }
+    }
+
+    fn resolve(&self) -> Vec<BacktraceSymbol> {
+        let frame = match self {
+            RawFrame::Actual(frame) => frame,
+            #[cfg(test)]
+            RawFrame::Fake => unimplemented!(),
+        };
+        let mut symbols = Vec::new();
+
+        // Use the global backtrace lock to synchronize this as it's a
+        // requirement of the `backtrace` crate, and then actually resolve
+        // the frame.
+        let _lock = lock();
+        unsafe {
+            backtrace::resolve_frame_unsynchronized(frame, |symbol| {
+                symbols.push(BacktraceSymbol {
+                    name: symbol.name().map(|m| m.as_bytes().to_vec()),
+                    filename: symbol.filename_raw().map(|b| match b {
+                        BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
+                        BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
+                    }),
+                    lineno: symbol.lineno(),
+                });
+            });
+        }
+        symbols
+    }
 }
 
 #[test]
 fn test_debug() {
     let backtrace = Backtrace {
-        inner: Inner::Captured(Mutex::new(Capture {
+        inner: Inner::Captured(Capture {
             actual_start: 1,
-            resolved: true,
             frames: vec![
                 BacktraceFrame {
                     frame: RawFrame::Fake,
-                    symbols: vec![BacktraceSymbol {
+                    symbols: SyncOnceCell::from(vec![BacktraceSymbol {
                         name: Some(b"std::backtrace::Backtrace::create".to_vec()),
                         filename: Some(BytesOrWide::Bytes(b"rust/backtrace.rs".to_vec())),
                         lineno: Some(100),
-                    }],
+                    }]),
                 },
                 BacktraceFrame {
                     frame: RawFrame::Fake,
-                    symbols: vec![BacktraceSymbol {
+                    symbols: SyncOnceCell::from(vec![BacktraceSymbol {
                         name: Some(b"__rust_maybe_catch_panic".to_vec()),
                         filename: None,
                         lineno: None,
-                    }],
+                    }]),
                 },
                 BacktraceFrame {
                     frame: RawFrame::Fake,
-                    symbols: vec![
+                    symbols: SyncOnceCell::from(vec![
                         BacktraceSymbol {
                             name: Some(b"std::rt::lang_start_internal".to_vec()),
                             filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
@@ -476,10 +644,10 @@ fn test_debug() {
                             filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
                             lineno: Some(400),
                         },
-                    ],
+                    ]),
                 },
             ],
-        })),
+        }),
     };
 
     #[rustfmt::skip]
@@ -491,3 +659,36 @@ fn test_debug() {
 
     assert_eq!(format!("{:#?}", backtrace), expected);
 }
+
+#[test]
+fn test_frames() {
+    let backtrace = Backtrace {
+        inner: Inner::Captured(Capture {
+            actual_start: 1,
+            frames: vec![
+                BacktraceFrame { frame: RawFrame::Fake, symbols: SyncOnceCell::from(vec![]) },
+                BacktraceFrame {
+                    frame: RawFrame::Fake,
+                    symbols: SyncOnceCell::from(vec![BacktraceSymbol {
+                        name: Some(b"_ZN3std2rt10lang_start17h0123456789abcdefE".to_vec()),
+                        filename: Some(BytesOrWide::Bytes(b"rust/rt.rs".to_vec())),
+                        lineno: Some(300),
+                    }]),
+                },
+            ],
+        }),
+    };
+
+    let frames = backtrace.frames();
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].ip(), 1 as *mut c_void);
+
+    let symbol = &frames[0].symbols()[0];
+    assert_eq!(symbol.name().as_deref(), Some("std::rt::lang_start"));
+    assert_eq!(symbol.name_bytes(), Some(&b"_ZN3std2rt10lang_start17h0123456789abcdefE"[..]));
+    assert_eq!(symbol.filename().as_deref(), Some(Path::new("rust/rt.rs")));
+    assert_eq!(symbol.lineno(), Some(300));
+
+    assert_eq!(backtrace.frame_addresses().to_string(), "0x1\n");
+    assert!(Backtrace::disabled().frames().is_empty());
+}
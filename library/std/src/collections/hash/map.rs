@@ -12,6 +12,7 @@ use crate::fmt::{self, Debug};
 use crate::hash::{BuildHasher, Hash, Hasher, SipHasher13};
 use crate::iter::{FromIterator, FusedIterator};
 use crate::ops::Index;
+use crate::ptr;
 use crate::sys;
 
 /// A hash map implemented with quadratic probing and SIMD lookup.
@@ -765,6 +766,135 @@ where
         self.base.get_mut(k)
     }
 
+    /// Attempts to get mutable references to `N` values in the map at once.
+    ///
+    /// Returns an array of length `N` with the results of each query. For soundness, at most
+    /// one mutable reference will be returned to any value. `None` will be returned if any of
+    /// the keys are duplicates or missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_many_mut)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut libraries = HashMap::new();
+    /// libraries.insert("Bodleian Library".to_string(), 1602);
+    /// libraries.insert("Athenæum".to_string(), 1807);
+    /// libraries.insert("Herzogin-Anna-Amalia-Bibliothek".to_string(), 1691);
+    /// libraries.insert("Library of Congress".to_string(), 1800);
+    ///
+    /// let got = libraries.get_many_mut([
+    ///     "Athenæum",
+    ///     "Library of Congress",
+    /// ]);
+    /// assert_eq!(
+    ///     got,
+    ///     Some([
+    ///         &mut 1807,
+    ///         &mut 1800,
+    ///     ]),
+    /// );
+    ///
+    /// // Missing keys result in None
+    /// let got = libraries.get_many_mut([
+    ///     "Athenæum",
+    ///     "New York Public Library",
+    /// ]);
+    /// assert_eq!(got, None);
+    ///
+    /// // Duplicate keys result in None
+    /// let got = libraries.get_many_mut([
+    ///     "Athenæum",
+    ///     "Athenæum",
+    /// ]);
+    /// assert_eq!(got, None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "map_many_mut", issue = "none")]
+    pub fn get_many_mut<Q: ?Sized, const N: usize>(&mut self, ks: [&Q; N]) -> Option<[&'_ mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let ptrs = self.get_many_mut_ptrs(ks)?;
+        for (i, ptr) in ptrs.iter().enumerate() {
+            if ptrs[..i].contains(ptr) {
+                return None;
+            }
+        }
+        // SAFETY: the pointers come from distinct entries of the map, which is
+        // mutably borrowed for as long as the references live.
+        Some(ptrs.map(|ptr| unsafe { &mut *ptr }))
+    }
+
+    /// Attempts to get mutable references to `N` values in the map at once, without validating
+    /// that the values are unique.
+    ///
+    /// Returns an array of length `N` with the results of each query. `None` will be returned if
+    /// any of the keys are missing.
+    ///
+    /// For a safe alternative see [`get_many_mut`](HashMap::get_many_mut).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with overlapping keys is *[undefined behavior]* even if the resulting
+    /// references are not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_many_mut)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut libraries = HashMap::new();
+    /// libraries.insert("Bodleian Library".to_string(), 1602);
+    /// libraries.insert("Athenæum".to_string(), 1807);
+    /// libraries.insert("Herzogin-Anna-Amalia-Bibliothek".to_string(), 1691);
+    /// libraries.insert("Library of Congress".to_string(), 1800);
+    ///
+    /// let got = unsafe {
+    ///     libraries.get_many_unchecked_mut(["Athenæum", "Library of Congress"])
+    /// };
+    /// assert_eq!(got, Some([&mut 1807, &mut 1800]));
+    ///
+    /// // Missing keys result in None
+    /// let got = unsafe {
+    ///     libraries.get_many_unchecked_mut(["Athenæum", "New York Public Library"])
+    /// };
+    /// assert_eq!(got, None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "map_many_mut", issue = "none")]
+    pub unsafe fn get_many_unchecked_mut<Q: ?Sized, const N: usize>(
+        &mut self,
+        ks: [&Q; N],
+    ) -> Option<[&'_ mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let ptrs = self.get_many_mut_ptrs(ks)?;
+        // SAFETY: the caller guarantees that the keys are distinct, so the
+        // pointers come from distinct entries of the map.
+        Some(ptrs.map(|ptr| unsafe { &mut *ptr }))
+    }
+
+    /// Looks up each of the keys, returning pointers to their values.
+    fn get_many_mut_ptrs<Q: ?Sized, const N: usize>(&mut self, ks: [&Q; N]) -> Option<[*mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let mut ptrs = [ptr::null_mut(); N];
+        for (slot, k) in ptrs.iter_mut().zip(ks.iter()) {
+            *slot = self.base.get_mut(*k)? as *mut V;
+        }
+        Some(ptrs)
+    }
+
     /// Inserts a key-value pair into the map.
     ///
     /// If the map did not have this key present, [`None`] is returned.
@@ -1223,6 +1353,35 @@ where
     }
 
     /// Creates a `RawEntryMut` from the given key and its hash.
+    ///
+    /// The hash is not checked against the key: it must be the hash of `k` computed with the
+    /// map's [`hasher`], or the lookup will miss the entry.
+    ///
+    /// [`hasher`]: HashMap::hasher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::RawEntryMut;
+    /// use std::hash::{BuildHasher, Hash, Hasher};
+    ///
+    /// let mut map: HashMap<String, u32> = HashMap::new();
+    ///
+    /// // Hash the borrowed form once, and reuse it for the lookup and the insertion.
+    /// let mut hasher = map.hasher().build_hasher();
+    /// "poneyland".hash(&mut hasher);
+    /// let hash = hasher.finish();
+    ///
+    /// match map.raw_entry_mut().from_key_hashed_nocheck(hash, "poneyland") {
+    ///     RawEntryMut::Occupied(_) => unreachable!(),
+    ///     RawEntryMut::Vacant(entry) => {
+    ///         entry.insert_hashed_nocheck(hash, "poneyland".to_string(), 3);
+    ///     }
+    /// }
+    /// assert_eq!(map["poneyland"], 3);
+    /// ```
     #[inline]
     #[unstable(feature = "hash_raw_entry", issue = "56167")]
     pub fn from_key_hashed_nocheck<Q: ?Sized>(self, hash: u64, k: &Q) -> RawEntryMut<'a, K, V, S>
@@ -1234,6 +1393,34 @@ where
     }
 
     /// Creates a `RawEntryMut` from the given hash.
+    ///
+    /// `is_match` is called on the keys stored under `hash` until it returns `true`, which
+    /// allows searching with a key type that has no [`Borrow`] relation to `K`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::RawEntryMut;
+    /// use std::hash::{BuildHasher, Hash, Hasher};
+    ///
+    /// let mut map: HashMap<Box<str>, u32> = HashMap::new();
+    /// map.insert("poneyland".into(), 3);
+    ///
+    /// let mut hasher = map.hasher().build_hasher();
+    /// "poneyland".hash(&mut hasher);
+    /// let hash = hasher.finish();
+    ///
+    /// // Compare the stored keys by their bytes instead of going through `Borrow`.
+    /// let key: &[u8] = b"poneyland";
+    /// if let RawEntryMut::Occupied(mut entry) =
+    ///     map.raw_entry_mut().from_hash(hash, |k| k.as_bytes() == key)
+    /// {
+    ///     *entry.get_mut() += 1;
+    /// }
+    /// assert_eq!(map["poneyland"], 4);
+    /// ```
     #[inline]
     #[unstable(feature = "hash_raw_entry", issue = "56167")]
     pub fn from_hash<F>(self, hash: u64, is_match: F) -> RawEntryMut<'a, K, V, S>
@@ -1260,14 +1447,19 @@ where
     }
 
     /// Access an entry by a key and its hash.
+    ///
+    /// The hash is not checked against the key: it must be the hash of `k` computed with the
+    /// map's [`hasher`], or the lookup will miss the entry.
+    ///
+    /// [`hasher`]: HashMap::hasher
     #[inline]
     #[unstable(feature = "hash_raw_entry", issue = "56167")]
     pub fn from_key_hashed_nocheck<Q: ?Sized>(self, hash: u64, k: &Q) -> Option<(&'a K, &'a V)>
     where
         K: Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Eq,
     {
-        self.map.base.raw_entry().from_key_hashed_nocheck(hash, k)
+        self.map.base.raw_entry().from_hash(hash, |q| q.borrow().eq(k))
     }
 
     /// Access an entry by hash.
@@ -1282,6 +1474,39 @@ where
 }
 
 impl<'a, K, V, S> RawEntryMut<'a, K, V, S> {
+    /// Sets the value of the entry, and returns a `RawOccupiedEntryMut`.
+    ///
+    /// If the entry is occupied, its key is left as it is and only the value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, u32> = HashMap::new();
+    /// let entry = map.raw_entry_mut().from_key("horseyland").insert("horseyland", 37);
+    ///
+    /// assert_eq!(entry.remove_entry(), ("horseyland", 37));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_raw_entry", issue = "56167")]
+    pub fn insert(self, key: K, value: V) -> RawOccupiedEntryMut<'a, K, V>
+    where
+        K: Hash,
+        S: BuildHasher,
+    {
+        match self {
+            RawEntryMut::Occupied(mut entry) => {
+                entry.insert(value);
+                entry
+            }
+            RawEntryMut::Vacant(entry) => RawOccupiedEntryMut {
+                base: base::RawEntryMut::Vacant(entry.base).insert(key, value),
+            },
+        }
+    }
+
     /// Ensures a value is in the entry by inserting the default if empty, and returns
     /// mutable references to the key and value in the entry.
     ///
@@ -1462,7 +1687,7 @@ impl<'a, K, V> RawOccupiedEntryMut<'a, K, V> {
         self.base.insert(value)
     }
 
-    /// Sets the value of the entry, and returns the entry's old value.
+    /// Sets the key of the entry, and returns the entry's old key.
     #[inline]
     #[unstable(feature = "hash_raw_entry", issue = "56167")]
     pub fn insert_key(&mut self, key: K) -> K {
@@ -1499,6 +1724,13 @@ impl<'a, K, V, S> RawVacantEntryMut<'a, K, V, S> {
 
     /// Sets the value of the entry with the VacantEntry's key,
     /// and returns a mutable reference to it.
+    ///
+    /// The key is stored under the given hash without hashing it again, so this is the
+    /// cheaper choice when the hash was already computed for the lookup. The hash must be
+    /// the one the map's [`hasher`] computes for `key`, or the key will not be found by
+    /// later lookups and may be moved to the wrong place when the map grows.
+    ///
+    /// [`hasher`]: HashMap::hasher
     #[inline]
     #[unstable(feature = "hash_raw_entry", issue = "56167")]
     pub fn insert_hashed_nocheck(self, hash: u64, key: K, value: V) -> (&'a mut K, &'a mut V)
@@ -3531,4 +3763,64 @@ mod test_map {
             }
         }
     }
+
+    #[test]
+    fn test_raw_entry_hashed_insert() {
+        use super::RawEntryMut::{Occupied, Vacant};
+        use core::hash::{BuildHasher, Hash, Hasher};
+
+        let mut map: HashMap<String, usize> = HashMap::new();
+        let hash_of = |map: &HashMap<String, usize>, k: &str| -> u64 {
+            let mut hasher = map.hasher().build_hasher();
+            k.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // Intern enough strings to make the map grow, so that entries inserted with a
+        // caller-supplied hash get rehashed and must still be found afterwards.
+        for i in 0..100 {
+            let s = i.to_string();
+            let hash = hash_of(&map, &s);
+            match map.raw_entry_mut().from_key_hashed_nocheck(hash, s.as_str()) {
+                Occupied(_) => unreachable!(),
+                Vacant(view) => {
+                    let (k, v) = view.insert_hashed_nocheck(hash, s.clone(), i);
+                    assert_eq!((k.as_str(), *v), (s.as_str(), i));
+                }
+            }
+        }
+        for i in 0..100 {
+            let s = i.to_string();
+            let hash = hash_of(&map, &s);
+            assert_eq!(map.raw_entry().from_key_hashed_nocheck(hash, s.as_str()).unwrap().1, &i);
+            assert_eq!(map.raw_entry().from_hash(hash, |k| *k == s).unwrap().1, &i);
+            assert_eq!(map[&s], i);
+        }
+
+        let entry = map.raw_entry_mut().from_key("7").insert("7".to_string(), 70);
+        assert_eq!(entry.get(), &70);
+        let entry = map.raw_entry_mut().from_key("new").insert("new".to_string(), 1);
+        assert_eq!(entry.key(), "new");
+        assert_eq!(map.len(), 101);
+        assert_eq!(map["7"], 70);
+    }
+
+    #[test]
+    fn test_get_many_mut() {
+        let mut map: HashMap<_, _> = (0..10).map(|i| (i, i * 10)).collect();
+
+        let [a, b, c] = map.get_many_mut([&1, &5, &9]).unwrap();
+        *a += 1;
+        *b += 5;
+        *c += 9;
+        assert_eq!((map[&1], map[&5], map[&9]), (11, 55, 99));
+
+        assert_eq!(map.get_many_mut([&1, &10]), None);
+        assert_eq!(map.get_many_mut([&1, &2, &1]), None);
+        assert_eq!(map.get_many_mut::<i32, 0>([]), Some([]));
+
+        let [x, y] = unsafe { map.get_many_unchecked_mut([&0, &3]) }.unwrap();
+        crate::mem::swap(x, y);
+        assert_eq!((map[&0], map[&3]), (30, 0));
+    }
 }
@@ -700,6 +700,162 @@ impl OsStr {
         unsafe { &*(&self.inner as *const _ as *const [u8]) }
     }
 
+    /// Converts bytes to an `OsStr` without checking that they are in the
+    /// encoding used by `OsStr` on this platform.
+    ///
+    /// The bytes are meant to come from [`as_encoded_bytes`], possibly split at
+    /// the boundaries of valid UTF-8 substrings of them.
+    ///
+    /// [`as_encoded_bytes`]: #method.as_encoded_bytes
+    ///
+    /// # Safety
+    ///
+    /// As the encoding is unspecified, callers must pass in bytes that
+    /// originated from [`as_encoded_bytes`] on the same platform and Rust
+    /// version, after splitting them only:
+    ///
+    /// - immediately before a valid non-empty UTF-8 substring, or
+    /// - immediately after a valid non-empty UTF-8 substring.
+    ///
+    /// [`as_encoded_bytes`]: #method.as_encoded_bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_bytes)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("Mary had a little lamb");
+    /// let bytes = os_str.as_encoded_bytes();
+    /// let words = bytes.split(|b| *b == b' ');
+    /// let words: Vec<&OsStr> = words
+    ///     .map(|word| {
+    ///         // SAFETY: `word` was split around an ASCII space.
+    ///         unsafe { OsStr::from_encoded_bytes_unchecked(word) }
+    ///     })
+    ///     .collect();
+    /// assert_eq!(words, ["Mary", "had", "a", "little", "lamb"]);
+    /// ```
+    #[unstable(feature = "os_str_bytes", issue = "none")]
+    #[inline]
+    pub unsafe fn from_encoded_bytes_unchecked(bytes: &[u8]) -> &OsStr {
+        // `OsStr` is a wrapper of `Slice`, which wraps bytes in the platform
+        // encoding, which the caller guarantees `bytes` to be in.
+        &*(bytes as *const [u8] as *const OsStr)
+    }
+
+    /// Converts this string to a byte slice, in the encoding that `OsStr`
+    /// uses internally on this platform.
+    ///
+    /// The encoding is an unspecified, platform-specific, self-synchronizing
+    /// superset of UTF-8: any valid UTF-8 in the string keeps its bytes, so
+    /// it can be searched for and split around. Anything else about the bytes
+    /// should be treated as opaque, and they can only be turned back into an
+    /// `OsStr` with [`from_encoded_bytes_unchecked`].
+    ///
+    /// [`from_encoded_bytes_unchecked`]: #method.from_encoded_bytes_unchecked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_bytes)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("ferris.rs");
+    /// assert_eq!(os_str.as_encoded_bytes(), b"ferris.rs");
+    /// ```
+    #[unstable(feature = "os_str_bytes", issue = "none")]
+    #[inline]
+    pub fn as_encoded_bytes(&self) -> &[u8] {
+        self.bytes()
+    }
+
+    /// Returns `true` if `needle` is a substring of this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_search)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("bananas");
+    /// assert!(os_str.contains("nana"));
+    /// assert!(!os_str.contains("apples"));
+    /// ```
+    #[unstable(feature = "os_str_search", issue = "none")]
+    #[inline]
+    pub fn contains(&self, needle: &str) -> bool {
+        self.bytes().find(needle.as_bytes()).is_some()
+    }
+
+    /// Returns `true` if this string starts with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_search)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("--verbose");
+    /// assert!(os_str.starts_with("--"));
+    /// assert!(!os_str.starts_with("-v"));
+    /// ```
+    #[unstable(feature = "os_str_search", issue = "none")]
+    #[inline]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.bytes().starts_with(prefix.as_bytes())
+    }
+
+    /// Returns this string with `prefix` removed, or `None` if it doesn't
+    /// start with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_search)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("--verbose");
+    /// assert_eq!(os_str.strip_prefix("--"), Some(OsStr::new("verbose")));
+    /// assert_eq!(os_str.strip_prefix("-v"), None);
+    /// ```
+    #[unstable(feature = "os_str_search", issue = "none")]
+    #[inline]
+    pub fn strip_prefix(&self, prefix: &str) -> Option<&OsStr> {
+        let rest = self.bytes().strip_prefix(prefix.as_bytes())?;
+        // SAFETY: `rest` follows the valid UTF-8 of `prefix`.
+        Some(unsafe { OsStr::from_encoded_bytes_unchecked(rest) })
+    }
+
+    /// Splits this string on the first occurrence of `delimiter`, returning
+    /// the parts before and after it, or `None` if it doesn't occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_search)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let os_str = OsStr::new("--color=always");
+    /// assert_eq!(os_str.split_once("="), Some((OsStr::new("--color"), OsStr::new("always"))));
+    /// assert_eq!(os_str.split_once(":"), None);
+    /// ```
+    #[unstable(feature = "os_str_search", issue = "none")]
+    #[inline]
+    pub fn split_once(&self, delimiter: &str) -> Option<(&OsStr, &OsStr)> {
+        let bytes = self.bytes();
+        let start = bytes.find(delimiter.as_bytes())?;
+        let end = start + delimiter.len();
+        // SAFETY: both parts are split right next to the valid UTF-8 of
+        // `delimiter`, and an empty `delimiter` is only found at the start.
+        unsafe {
+            Some((
+                OsStr::from_encoded_bytes_unchecked(&bytes[..start]),
+                OsStr::from_encoded_bytes_unchecked(&bytes[end..]),
+            ))
+        }
+    }
+
     /// Converts this string to its ASCII lower case equivalent in-place.
     ///
     /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z',
@@ -1362,4 +1518,33 @@ mod tests {
         assert_eq!(&*rc2, os_str);
         assert_eq!(&*arc2, os_str);
     }
+
+    #[test]
+    fn test_os_str_search() {
+        let os_str = OsStr::new("--color=always");
+        assert!(os_str.contains("="));
+        assert!(os_str.contains(""));
+        assert!(!os_str.contains("never"));
+        assert!(os_str.starts_with("--"));
+        assert!(!os_str.starts_with("-c"));
+        assert_eq!(os_str.strip_prefix("--"), Some(OsStr::new("color=always")));
+        assert_eq!(os_str.strip_prefix("always"), None);
+        assert_eq!(os_str.split_once("="), Some((OsStr::new("--color"), OsStr::new("always"))));
+        assert_eq!(os_str.split_once(""), Some((OsStr::new(""), os_str)));
+        assert_eq!(os_str.split_once(":"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_os_str_search_non_utf8() {
+        use crate::os::unix::ffi::OsStrExt;
+
+        let os_str = OsStr::from_bytes(b"\xff\xfe=\xc3");
+        assert_eq!(os_str.as_encoded_bytes(), b"\xff\xfe=\xc3");
+        assert!(os_str.contains("="));
+        let (key, value) = os_str.split_once("=").unwrap();
+        assert_eq!(key.as_encoded_bytes(), b"\xff\xfe");
+        assert_eq!(value.as_encoded_bytes(), b"\xc3");
+        assert!(!os_str.starts_with("\u{ff}"));
+    }
 }
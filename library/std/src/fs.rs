@@ -12,7 +12,7 @@
 
 use crate::ffi::OsString;
 use crate::fmt;
-use crate::io::{self, Initializer, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+use crate::io::{self, IoSlice, IoSliceMut, Read, ReadBuf, Seek, SeekFrom, Write};
 use crate::path::{Path, PathBuf};
 use crate::sys::fs as fs_imp;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
@@ -625,6 +625,196 @@ impl File {
     pub fn set_permissions(&self, perm: Permissions) -> io::Result<()> {
         self.inner.set_permissions(perm.0)
     }
+
+    /// Acquires a shared (read) lock on the whole file, blocking until the
+    /// lock can be acquired.
+    ///
+    /// Any number of handles may hold a shared lock on a file at the same
+    /// time, but not while another handle holds an exclusive lock on it.
+    ///
+    /// File locks are *advisory*: they only affect other callers of the
+    /// locking methods and do not prevent anyone from reading or writing the
+    /// file. A lock is associated with the open file description, so it is
+    /// shared by handles created with [`try_clone`] and is released when the
+    /// last of them is dropped or when [`unlock`] is called.
+    ///
+    /// If this handle already holds a lock on the file, the behavior is
+    /// unspecified and platform-specific, and may include deadlocking: on
+    /// Unix the existing lock is converted, while on Windows this may block
+    /// forever if the existing lock is exclusive.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH` flag, and the `LockFileEx` function on Windows.
+    /// Other platforms return an error. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`try_clone`]: #method.try_clone
+    /// [`unlock`]: #method.unlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.lock_shared()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Acquires an exclusive (write) lock on the whole file, blocking until
+    /// the lock can be acquired.
+    ///
+    /// While a handle holds an exclusive lock, no other handle may hold any
+    /// lock on the file. See [`lock_shared`] for the advisory nature and
+    /// lifetime of file locks, and for what happens if this handle is
+    /// already locked.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX` flag, and the `LockFileEx` function on Windows with
+    /// the `LOCKFILE_EXCLUSIVE_LOCK` flag. Other platforms return an error.
+    /// Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`lock_shared`]: #method.lock_shared
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.lock_exclusive()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.inner.lock_exclusive()
+    }
+
+    /// Attempts to acquire a shared (read) lock on the whole file without
+    /// blocking.
+    ///
+    /// This behaves like [`lock_shared`], except that if the lock cannot be
+    /// acquired immediately an error of kind [`ErrorKind::WouldBlock`] is
+    /// returned.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH | LOCK_NB` flags, and the `LockFileEx` function on
+    /// Windows with the `LOCKFILE_FAIL_IMMEDIATELY` flag. Other platforms
+    /// return an error. Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`lock_shared`]: #method.lock_shared
+    /// [`ErrorKind::WouldBlock`]: ../io/enum.ErrorKind.html#variant.WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    /// use std::io::ErrorKind;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     match f.try_lock_shared() {
+    ///         Ok(()) => println!("locked"),
+    ///         Err(e) if e.kind() == ErrorKind::WouldBlock => println!("someone else is writing"),
+    ///         Err(e) => return Err(e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Attempts to acquire an exclusive (write) lock on the whole file
+    /// without blocking.
+    ///
+    /// This behaves like [`lock_exclusive`], except that if the lock cannot
+    /// be acquired immediately an error of kind [`ErrorKind::WouldBlock`] is
+    /// returned.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX | LOCK_NB` flags, and the `LockFileEx` function on
+    /// Windows with the `LOCKFILE_EXCLUSIVE_LOCK | LOCKFILE_FAIL_IMMEDIATELY`
+    /// flags. Other platforms return an error. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    /// [`lock_exclusive`]: #method.lock_exclusive
+    /// [`ErrorKind::WouldBlock`]: ../io/enum.ErrorKind.html#variant.WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.try_lock_exclusive()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_exclusive(&self) -> io::Result<()> {
+        self.inner.try_lock_exclusive()
+    }
+
+    /// Releases all locks held on the file through this handle.
+    ///
+    /// Locks are also released automatically when the last handle to the
+    /// open file is closed, so calling this is only needed to give up a lock
+    /// early. Unlocking a file that is not locked is not an error.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_UN` flag, and the `UnlockFile` function on Windows.
+    /// Other platforms return an error. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: ../io/index.html#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.lock_exclusive()?;
+    ///     // write to the file...
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
 }
 
 impl AsInner<fs_imp::File> for File {
@@ -665,10 +855,8 @@ impl Read for File {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        // SAFETY: Read is guaranteed to work on uninitialized memory
-        unsafe { Initializer::nop() }
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.inner.read_buf(buf)
     }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -711,10 +899,8 @@ impl Read for &File {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        // SAFETY: Read is guaranteed to work on uninitialized memory
-        unsafe { Initializer::nop() }
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.inner.read_buf(buf)
     }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1411,6 +1597,12 @@ impl Iterator for ReadDir {
     }
 }
 
+impl FromInner<fs_imp::ReadDir> for ReadDir {
+    fn from_inner(inner: fs_imp::ReadDir) -> ReadDir {
+        ReadDir(inner)
+    }
+}
+
 impl DirEntry {
     /// Returns the full path to the file that this entry represents.
     ///
@@ -2270,7 +2462,8 @@ mod tests {
     use crate::io::prelude::*;
 
     use crate::fs::{self, File, OpenOptions};
-    use crate::io::{ErrorKind, SeekFrom};
+    use crate::io::{ErrorKind, ReadBuf, SeekFrom};
+    use crate::mem::MaybeUninit;
     use crate::path::Path;
     use crate::str;
     use crate::sys_common::io::test::{tmpdir, TempDir};
@@ -2371,6 +2564,71 @@ mod tests {
         check!(fs::remove_file(filename));
     }
 
+    #[test]
+    fn file_test_io_read_buf() {
+        let message = "it's alright. have a good time";
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_rt_io_file_test_read_buf.txt");
+        check!(fs::write(filename, message));
+
+        let mut read_stream = check!(File::open(filename));
+        let mut buf = [MaybeUninit::uninit(); 1028];
+        let mut buf = ReadBuf::uninit(&mut buf);
+        check!(read_stream.read_buf(&mut buf));
+        assert_eq!(buf.filled(), message.as_bytes());
+        // The reader only initializes what it reads.
+        #[cfg(any(unix, windows))]
+        assert_eq!(buf.initialized_len(), message.len());
+
+        check!(read_stream.read_buf(&mut buf));
+        assert_eq!(buf.filled_len(), message.len());
+    }
+
+    #[test]
+    #[cfg(any(windows, target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+    fn file_test_lock() {
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_lock_test.txt");
+        check!(fs::write(filename, b"locked"));
+
+        let f1 = check!(File::open(filename));
+        let f2 = check!(File::open(filename));
+
+        // Shared locks can be held by several handles at once.
+        check!(f1.lock_shared());
+        check!(f2.try_lock_shared());
+        check!(f2.unlock());
+        assert_eq!(f2.try_lock_exclusive().unwrap_err().kind(), ErrorKind::WouldBlock);
+        check!(f1.unlock());
+
+        // An exclusive lock excludes every other lock.
+        check!(f1.lock_exclusive());
+        assert_eq!(f2.try_lock_shared().unwrap_err().kind(), ErrorKind::WouldBlock);
+        assert_eq!(f2.try_lock_exclusive().unwrap_err().kind(), ErrorKind::WouldBlock);
+
+        // Dropping the handle releases its lock.
+        drop(f1);
+        check!(f2.try_lock_exclusive());
+        check!(f2.unlock());
+    }
+
+    #[test]
+    #[cfg(any(windows, target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+    fn file_test_unlock_unlocked() {
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_unlock_unlocked_test.txt");
+        check!(fs::write(filename, b"unlocked"));
+
+        // Unlocking a file that was never locked succeeds.
+        let f = check!(File::open(filename));
+        check!(f.unlock());
+
+        // So does unlocking it a second time.
+        check!(f.lock_shared());
+        check!(f.unlock());
+        check!(f.unlock());
+    }
+
     #[test]
     fn invalid_path_raises() {
         let tmpdir = tmpdir();
@@ -2842,6 +3100,23 @@ mod tests {
         assert!(canary.exists());
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn recursive_rmdir_deep() {
+        // Deeper than the default limit of open file descriptors on many systems.
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("deep");
+        let mut dir = root.clone();
+        for _ in 0..1100 {
+            dir.push("d");
+        }
+        check!(fs::create_dir_all(&dir));
+        check!(fs::write(dir.join("f"), b"foo"));
+        check!(fs::remove_dir_all(&root));
+
+        assert!(!root.exists());
+    }
+
     #[test]
     fn recursive_rmdir_of_symlink() {
         // test we do not recursively delete a symlink but only dirs.
@@ -2858,6 +3133,46 @@ mod tests {
         assert!(canary.exists());
     }
 
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+    fn dir_relative_ops() {
+        use crate::os::unix::fs::Dir;
+
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("root");
+        check!(fs::create_dir_all(root.join("sub")));
+        check!(fs::write(root.join("a"), b"a"));
+        check!(symlink_dir(&tmpdir.path(), &root.join("link")));
+
+        let dir = check!(Dir::open(&root));
+        let mut b = check!(dir.open_at("b", OpenOptions::new().write(true).create(true)));
+        check!(b.write(b"bb"));
+        assert_eq!(check!(dir.metadata_at("b")).len(), 2);
+        assert!(check!(dir.symlink_metadata_at("link")).file_type().is_symlink());
+        assert!(check!(dir.metadata_at("link")).is_dir());
+
+        // Reading twice starts over from the beginning each time.
+        for _ in 0..2 {
+            let mut names: Vec<_> =
+                check!(dir.read_dir()).map(|e| check!(e).file_name()).collect();
+            names.sort();
+            assert_eq!(names, ["a", "b", "link", "sub"]);
+        }
+
+        // Descending into a symlink is refused.
+        assert!(dir.open_dir_at("link").is_err());
+        let sub = check!(dir.open_dir_at("sub"));
+        check!(dir.rename_at("b", &sub, "c"));
+        assert_eq!(check!(fs::read(root.join("sub/c"))), b"bb");
+
+        check!(dir.remove_at("a"));
+        check!(dir.remove_at("link"));
+        check!(sub.remove_at("c"));
+        check!(dir.remove_dir_at("sub"));
+        assert_eq!(check!(dir.read_dir()).count(), 0);
+        assert!(tmpdir.path().exists());
+    }
+
     #[test]
     // only Windows makes a distinction between file and directory symlinks.
     #[cfg(windows)]
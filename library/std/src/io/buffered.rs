@@ -6,8 +6,9 @@ use crate::cmp;
 use crate::error;
 use crate::fmt;
 use crate::io::{
-    self, Error, ErrorKind, Initializer, IoSlice, IoSliceMut, SeekFrom, DEFAULT_BUF_SIZE,
+    self, Error, ErrorKind, IoSlice, IoSliceMut, ReadBuf, SeekFrom, DEFAULT_BUF_SIZE,
 };
+use crate::mem::MaybeUninit;
 use crate::memchr;
 
 /// The `BufReader<R>` struct adds buffering to any reader.
@@ -53,9 +54,13 @@ use crate::memchr;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct BufReader<R> {
     inner: R,
-    buf: Box<[u8]>,
+    buf: Box<[MaybeUninit<u8>]>,
     pos: usize,
     cap: usize,
+    // The number of bytes at the start of `buf` that have been initialized by
+    // a previous read. Tracking this avoids zeroing the buffer again for
+    // readers that need an initialized buffer.
+    init: usize,
 }
 
 impl<R: Read> BufReader<R> {
@@ -97,12 +102,8 @@ impl<R: Read> BufReader<R> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn with_capacity(capacity: usize, inner: R) -> BufReader<R> {
-        unsafe {
-            let mut buffer = Vec::with_capacity(capacity);
-            buffer.set_len(capacity);
-            inner.initializer().initialize(&mut buffer);
-            BufReader { inner, buf: buffer.into_boxed_slice(), pos: 0, cap: 0 }
-        }
+        let buf = Box::new_uninit_slice(capacity);
+        BufReader { inner, buf, pos: 0, cap: 0, init: 0 }
     }
 }
 
@@ -176,7 +177,8 @@ impl<R> BufReader<R> {
     /// ```
     #[stable(feature = "bufreader_buffer", since = "1.37.0")]
     pub fn buffer(&self) -> &[u8] {
-        &self.buf[self.pos..self.cap]
+        // SAFETY: `self.cap` bytes of the buffer have been filled by the inner reader
+        unsafe { MaybeUninit::slice_get_ref(&self.buf[self.pos..self.cap]) }
     }
 
     /// Returns the number of bytes the internal buffer can hold at once.
@@ -295,9 +297,23 @@ impl<R: Read> Read for BufReader<R> {
         self.inner.is_read_vectored()
     }
 
-    // we can't skip unconditionally because of the large buffer case in read.
-    unsafe fn initializer(&self) -> Initializer {
-        self.inner.initializer()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        // If we don't have any buffered data and we're doing a massive read
+        // (larger than our internal buffer), bypass our internal buffer
+        // entirely.
+        if self.pos == self.cap && buf.remaining() >= self.buf.len() {
+            self.discard_buffer();
+            return self.inner.read_buf(buf);
+        }
+
+        let prev = buf.filled_len();
+
+        let mut rem = self.fill_buf()?;
+        rem.read_buf(buf)?;
+
+        self.consume(buf.filled_len() - prev); // slice impl of read_buf is known to never unfill buf
+
+        Ok(())
     }
 }
 
@@ -310,10 +326,20 @@ impl<R: Read> BufRead for BufReader<R> {
         // to tell the compiler that the pos..cap slice is always valid.
         if self.pos >= self.cap {
             debug_assert!(self.pos == self.cap);
-            self.cap = self.inner.read(&mut self.buf)?;
+
+            let mut readbuf = ReadBuf::uninit(&mut self.buf);
+            // SAFETY: `self.init` bytes of the buffer were initialized by a previous read
+            unsafe {
+                readbuf.assume_init(self.init);
+            }
+
+            self.inner.read_buf(&mut readbuf)?;
+
+            self.cap = readbuf.filled_len();
+            self.init = readbuf.initialized_len();
             self.pos = 0;
         }
-        Ok(&self.buf[self.pos..self.cap])
+        Ok(self.buffer())
     }
 
     fn consume(&mut self, amt: usize) {
@@ -1124,7 +1150,8 @@ where
 #[cfg(test)]
 mod tests {
     use crate::io::prelude::*;
-    use crate::io::{self, BufReader, BufWriter, IoSlice, LineWriter, SeekFrom};
+    use crate::io::{self, BufReader, BufWriter, IoSlice, LineWriter, ReadBuf, SeekFrom};
+    use crate::mem::MaybeUninit;
     use crate::sync::atomic::{AtomicUsize, Ordering};
     use crate::thread;
 
@@ -1176,6 +1203,49 @@ mod tests {
         assert_eq!(reader.read(&mut buf).unwrap(), 0);
     }
 
+    #[test]
+    fn test_buffered_reader_read_buf() {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::with_capacity(2, inner);
+
+        let mut buf = [MaybeUninit::uninit(); 3];
+        let mut buf = ReadBuf::uninit(&mut buf);
+
+        reader.read_buf(&mut buf).unwrap();
+        assert_eq!(buf.filled(), [5, 6, 7]);
+        assert_eq!(reader.buffer(), []);
+
+        let mut buf = [MaybeUninit::uninit(); 2];
+        let mut buf = ReadBuf::uninit(&mut buf);
+
+        reader.read_buf(&mut buf).unwrap();
+        assert_eq!(buf.filled(), [0, 1]);
+        assert_eq!(reader.buffer(), []);
+
+        let mut buf = [MaybeUninit::uninit(); 1];
+        let mut buf = ReadBuf::uninit(&mut buf);
+
+        reader.read_buf(&mut buf).unwrap();
+        assert_eq!(buf.filled(), [2]);
+        assert_eq!(reader.buffer(), [3]);
+
+        let mut buf = [MaybeUninit::uninit(); 3];
+        let mut buf = ReadBuf::uninit(&mut buf);
+
+        reader.read_buf(&mut buf).unwrap();
+        assert_eq!(buf.filled(), [3]);
+        assert_eq!(reader.buffer(), []);
+
+        reader.read_buf(&mut buf).unwrap();
+        assert_eq!(buf.filled(), [3, 4]);
+        assert_eq!(reader.buffer(), []);
+
+        buf.clear();
+
+        reader.read_buf(&mut buf).unwrap();
+        assert_eq!(buf.filled_len(), 0);
+    }
+
     #[test]
     fn test_buffered_reader_seek() {
         let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
@@ -1,7 +1,7 @@
 use crate::io::prelude::*;
 
 use crate::cmp;
-use crate::io::{self, Error, ErrorKind, Initializer, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, ReadBuf, SeekFrom};
 
 use core::convert::TryInto;
 
@@ -277,9 +277,14 @@ where
         Ok(())
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        let prev_filled = buf.filled_len();
+
+        Read::read_buf(&mut self.fill_buf()?, buf)?;
+
+        self.pos += (buf.filled_len() - prev_filled) as u64;
+
+        Ok(())
     }
 }
 
@@ -455,7 +460,8 @@ impl Write for Cursor<Box<[u8]>> {
 #[cfg(test)]
 mod tests {
     use crate::io::prelude::*;
-    use crate::io::{Cursor, IoSlice, IoSliceMut, SeekFrom};
+    use crate::io::{Cursor, IoSlice, IoSliceMut, ReadBuf, SeekFrom};
+    use crate::mem::MaybeUninit;
 
     #[test]
     fn test_vec_writer() {
@@ -651,6 +657,24 @@ mod tests {
         assert_eq!(reader.read(&mut buf).unwrap(), 0);
     }
 
+    #[test]
+    fn test_mem_reader_read_buf() {
+        let mut reader = Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        let mut buf = [MaybeUninit::uninit(); 5];
+        let mut rbuf = ReadBuf::uninit(&mut buf);
+        reader.read_buf(&mut rbuf).unwrap();
+        assert_eq!(rbuf.filled(), [0, 1, 2, 3, 4]);
+        assert_eq!(rbuf.initialized_len(), 5);
+        assert_eq!(reader.position(), 5);
+        rbuf.clear();
+        reader.read_buf(&mut rbuf).unwrap();
+        assert_eq!(rbuf.filled(), [5, 6, 7]);
+        assert_eq!(reader.position(), 8);
+        rbuf.clear();
+        reader.read_buf(&mut rbuf).unwrap();
+        assert_eq!(rbuf.filled(), []);
+    }
+
     #[test]
     fn test_mem_reader_vectored() {
         let mut reader = Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7]);
@@ -251,9 +251,9 @@
 
 use crate::cmp;
 use crate::fmt;
+use crate::mem::MaybeUninit;
 use crate::memchr;
 use crate::ops::{Deref, DerefMut};
-use crate::ptr;
 use crate::slice;
 use crate::str;
 use crate::sys;
@@ -266,6 +266,8 @@ pub use self::buffered::{BufReader, BufWriter, LineWriter};
 pub use self::cursor::Cursor;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::error::{Error, ErrorKind, Result};
+#[unstable(feature = "read_buf", issue = "none")]
+pub use self::readbuf::ReadBuf;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::stdio::{stderr, stdin, stdout, Stderr, Stdin, Stdout};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -278,12 +280,15 @@ pub use self::stdio::{set_panic, set_print};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::util::{copy, empty, repeat, sink, Empty, Repeat, Sink};
 
+pub(crate) use self::stdio::cleanup;
+pub(crate) use self::util::generic_copy;
+
 mod buffered;
 mod cursor;
 mod error;
 mod impls;
-mod lazy;
 pub mod prelude;
+mod readbuf;
 mod stdio;
 mod util;
 
@@ -345,8 +350,10 @@ where
 // time is 4,500 times (!) slower than a default reservation size of 32 if the
 // reader has a very small amount of data to return.
 //
-// Because we're extending the buffer with uninitialized data for trusted
-// readers, we need to make sure to truncate that if any of this panics.
+// The spare capacity of the vector is handed to `read_buf` as uninitialized
+// memory, and the length is only extended over the bytes the reader reports as
+// filled. Bytes the reader initialized but did not fill are remembered across
+// iterations so they are not zeroed again.
 fn read_to_end<R: Read + ?Sized>(r: &mut R, buf: &mut Vec<u8>) -> Result<usize> {
     read_to_end_with_reservation(r, buf, |_| 32)
 }
@@ -361,41 +368,52 @@ where
     F: FnMut(&R) -> usize,
 {
     let start_len = buf.len();
-    let mut g = Guard { len: buf.len(), buf };
-    let ret;
+    // Bytes past `buf.len()` that a previous read initialized but did not fill.
+    let mut initialized = 0;
     loop {
-        if g.len == g.buf.len() {
-            unsafe {
-                // FIXME(danielhenrymantilla): #42788
-                //
-                //   - This creates a (mut) reference to a slice of
-                //     _uninitialized_ integers, which is **undefined behavior**
-                //
-                //   - Only the standard library gets to soundly "ignore" this,
-                //     based on its privileged knowledge of unstable rustc
-                //     internals;
-                g.buf.reserve(reservation_size(r));
-                let capacity = g.buf.capacity();
-                g.buf.set_len(capacity);
-                r.initializer().initialize(&mut g.buf[g.len..]);
-            }
+        if buf.len() == buf.capacity() {
+            buf.reserve(reservation_size(r));
         }
 
-        match r.read(&mut g.buf[g.len..]) {
-            Ok(0) => {
-                ret = Ok(g.len - start_len);
-                break;
-            }
-            Ok(n) => g.len += n,
-            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
-            Err(e) => {
-                ret = Err(e);
-                break;
-            }
+        let len = buf.len();
+        let spare = buf.capacity() - len;
+        // SAFETY: the spare capacity of a vector is valid for writes, and
+        // `MaybeUninit<u8>` makes no claim about its contents.
+        let spare = unsafe {
+            slice::from_raw_parts_mut(buf.as_mut_ptr().add(len) as *mut MaybeUninit<u8>, spare)
+        };
+        let mut read_buf = ReadBuf::uninit(spare);
+        // SAFETY: these bytes were initialized by the previous read.
+        unsafe {
+            read_buf.assume_init(initialized);
+        }
+
+        match r.read_buf(&mut read_buf) {
+            Ok(()) => {}
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+
+        let filled = read_buf.filled_len();
+        if filled == 0 {
+            return Ok(buf.len() - start_len);
+        }
+        initialized = read_buf.initialized_len() - filled;
+
+        // SAFETY: `ReadBuf` guarantees that the filled bytes are initialized.
+        unsafe {
+            buf.set_len(len + filled);
         }
     }
+}
 
-    ret
+pub(crate) fn default_read_buf<F>(read: F, buf: &mut ReadBuf<'_>) -> Result<()>
+where
+    F: FnOnce(&mut [u8]) -> Result<usize>,
+{
+    let n = read(buf.initialize_unfilled())?;
+    buf.add_filled(n);
+    Ok(())
 }
 
 pub(crate) fn default_read_vectored<F>(read: F, bufs: &mut [IoSliceMut<'_>]) -> Result<usize>
@@ -585,29 +603,23 @@ pub trait Read {
         false
     }
 
-    /// Determines if this `Read`er can work with buffers of uninitialized
-    /// memory.
-    ///
-    /// The default implementation returns an initializer which will zero
-    /// buffers.
+    /// Pull some bytes from this source into the specified buffer.
     ///
-    /// If a `Read`er guarantees that it can work properly with uninitialized
-    /// memory, it should call [`Initializer::nop()`]. See the documentation for
-    /// [`Initializer`] for details.
+    /// This is equivalent to the [`read`](Read::read) method, except that it
+    /// is passed a [`ReadBuf`] rather than `[u8]` to allow use with
+    /// uninitialized buffers. The new data will be appended to any existing
+    /// contents of `buf`.
     ///
-    /// The behavior of this method must be independent of the state of the
-    /// `Read`er - the method only takes `&self` so that it can be used through
-    /// trait objects.
+    /// The default implementation delegates to `read`, zeroing the unfilled
+    /// part of the buffer first if it has not been initialized yet. Readers
+    /// that never look at the contents of the buffer they are given should
+    /// override this to read directly into the uninitialized memory.
     ///
-    /// # Safety
-    ///
-    /// This method is unsafe because a `Read`er could otherwise return a
-    /// non-zeroing `Initializer` from another `Read` type without an `unsafe`
-    /// block.
-    #[unstable(feature = "read_initializer", issue = "42788")]
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::zeroing()
+    /// Implementations must not de-initialize any part of `buf`, and must
+    /// only report bytes as filled that they actually wrote.
+    #[unstable(feature = "read_buf", issue = "none")]
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> Result<()> {
+        default_read_buf(|b| self.read(b), buf)
     }
 
     /// Read all bytes until EOF in this source, placing them into `buf`.
@@ -1149,50 +1161,6 @@ impl<'a> Deref for IoSlice<'a> {
     }
 }
 
-/// A type used to conditionally initialize buffers passed to `Read` methods.
-#[unstable(feature = "read_initializer", issue = "42788")]
-#[derive(Debug)]
-pub struct Initializer(bool);
-
-impl Initializer {
-    /// Returns a new `Initializer` which will zero out buffers.
-    #[unstable(feature = "read_initializer", issue = "42788")]
-    #[inline]
-    pub fn zeroing() -> Initializer {
-        Initializer(true)
-    }
-
-    /// Returns a new `Initializer` which will not zero out buffers.
-    ///
-    /// # Safety
-    ///
-    /// This may only be called by `Read`ers which guarantee that they will not
-    /// read from buffers passed to `Read` methods, and that the return value of
-    /// the method accurately reflects the number of bytes that have been
-    /// written to the head of the buffer.
-    #[unstable(feature = "read_initializer", issue = "42788")]
-    #[inline]
-    pub unsafe fn nop() -> Initializer {
-        Initializer(false)
-    }
-
-    /// Indicates if a buffer should be initialized.
-    #[unstable(feature = "read_initializer", issue = "42788")]
-    #[inline]
-    pub fn should_initialize(&self) -> bool {
-        self.0
-    }
-
-    /// Initializes a buffer if necessary.
-    #[unstable(feature = "read_initializer", issue = "42788")]
-    #[inline]
-    pub fn initialize(&self, buf: &mut [u8]) {
-        if self.should_initialize() {
-            unsafe { ptr::write_bytes(buf.as_mut_ptr(), 0, buf.len()) }
-        }
-    }
-}
-
 /// A trait for objects which are byte-oriented sinks.
 ///
 /// Implementors of the `Write` trait are sometimes called 'writers'.
@@ -2173,11 +2141,6 @@ impl<T: Read, U: Read> Read for Chain<T, U> {
         }
         self.second.read_vectored(bufs)
     }
-
-    unsafe fn initializer(&self) -> Initializer {
-        let initializer = self.first.initializer();
-        if initializer.should_initialize() { initializer } else { self.second.initializer() }
-    }
 }
 
 #[stable(feature = "chain_bufread", since = "1.9.0")]
@@ -2365,8 +2328,44 @@ impl<T: Read> Read for Take<T> {
         Ok(n)
     }
 
-    unsafe fn initializer(&self) -> Initializer {
-        self.inner.initializer()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> Result<()> {
+        // Don't call into inner reader at all at EOF because it may still block
+        if self.limit == 0 {
+            return Ok(());
+        }
+
+        let prev_filled = buf.filled_len();
+
+        if self.limit <= buf.remaining() as u64 {
+            let limit = self.limit as usize;
+            let extra_init = cmp::min(limit, buf.initialized_len() - prev_filled);
+
+            // SAFETY: no uninitialized data is written to `ibuf`
+            let ibuf = unsafe { &mut buf.unfilled_mut()[..limit] };
+            let mut sliced_buf = ReadBuf::uninit(ibuf);
+            // SAFETY: `extra_init` bytes of `ibuf` are known to be initialized
+            unsafe {
+                sliced_buf.assume_init(extra_init);
+            }
+
+            self.inner.read_buf(&mut sliced_buf)?;
+
+            let new_init = sliced_buf.initialized_len();
+            let filled = sliced_buf.filled_len();
+
+            // SAFETY: `new_init` bytes of the unfilled part of `buf` have been initialized
+            unsafe {
+                buf.assume_init(new_init);
+            }
+            buf.add_filled(filled);
+            self.limit -= filled as u64;
+        } else {
+            self.inner.read_buf(buf)?;
+            // The inner reader may shrink the filled region.
+            self.limit -= buf.filled_len().saturating_sub(prev_filled) as u64;
+        }
+
+        Ok(())
     }
 
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
@@ -2499,7 +2498,8 @@ mod tests {
     use super::{repeat, Cursor, SeekFrom};
     use crate::cmp::{self, min};
     use crate::io::prelude::*;
-    use crate::io::{self, IoSlice, IoSliceMut};
+    use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
+    use crate::mem::MaybeUninit;
     use crate::ops::Deref;
 
     #[test]
@@ -2590,6 +2590,51 @@ mod tests {
         assert_eq!(v, data);
     }
 
+    #[test]
+    fn read_to_end_uninit() {
+        // A reader that only ever fills its buffers through `read_buf` and
+        // never initializes more than it fills.
+        struct R(usize);
+
+        impl Read for R {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                panic!("`read` should not be called")
+            }
+
+            fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+                let n = cmp::min(self.0, buf.remaining());
+                buf.append(&vec![7; n]);
+                self.0 -= n;
+                Ok(())
+            }
+        }
+
+        let mut v = vec![1, 2];
+        assert_eq!(R(100).read_to_end(&mut v).unwrap(), 100);
+        assert_eq!(v.len(), 102);
+        assert_eq!(&v[..2], [1, 2]);
+        assert!(v[2..].iter().all(|&b| b == 7));
+    }
+
+    #[test]
+    fn take_read_buf() {
+        let mut buf = [MaybeUninit::uninit(); 8];
+        let mut rbuf = ReadBuf::uninit(&mut buf);
+
+        let mut r = (&b"hello world"[..]).take(5);
+        r.read_buf(&mut rbuf).unwrap();
+        assert_eq!(rbuf.filled(), b"hello");
+        assert_eq!(r.limit(), 0);
+
+        r.read_buf(&mut rbuf).unwrap();
+        assert_eq!(rbuf.filled(), b"hello");
+
+        let mut r = (&b"hello world"[..]).take(100);
+        r.read_buf(&mut rbuf).unwrap();
+        assert_eq!(rbuf.filled(), b"hellohel");
+        assert_eq!(r.limit(), 97);
+    }
+
     #[test]
     fn read_to_string() {
         let mut c = Cursor::new(&b""[..]);
@@ -4,9 +4,9 @@ use crate::io::prelude::*;
 
 use crate::cell::RefCell;
 use crate::fmt;
-use crate::io::lazy::Lazy;
-use crate::io::{self, BufReader, Initializer, IoSlice, IoSliceMut, LineWriter};
-use crate::sync::{Arc, Mutex, MutexGuard, Once};
+use crate::io::{self, BufReader, IoSlice, IoSliceMut, LineWriter, ReadBuf};
+use crate::lazy::{SyncLazy, SyncOnceCell};
+use crate::sync::{Mutex, MutexGuard, Once};
 use crate::sys::stdio;
 use crate::sys_common::remutex::{ReentrantMutex, ReentrantMutexGuard};
 use crate::thread::LocalKey;
@@ -92,9 +92,8 @@ impl Read for StdinRaw {
         self.0.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
     }
 
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
@@ -231,6 +230,13 @@ impl<R: io::Read> io::Read for Maybe<R> {
             Maybe::Fake => true,
         }
     }
+
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        match self {
+            Maybe::Real(r) => handle_ebadf(r.read_buf(buf), ()),
+            Maybe::Fake => Ok(()),
+        }
+    }
 }
 
 fn handle_ebadf<T>(r: io::Result<T>, default: T) -> io::Result<T> {
@@ -275,7 +281,7 @@ fn handle_ebadf<T>(r: io::Result<T>, default: T) -> io::Result<T> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Stdin {
-    inner: Arc<Mutex<BufReader<Maybe<StdinRaw>>>>,
+    inner: &'static Mutex<BufReader<Maybe<StdinRaw>>>,
 }
 
 /// A locked reference to the `Stdin` handle.
@@ -356,20 +362,14 @@ pub struct StdinLock<'a> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn stdin() -> Stdin {
-    static INSTANCE: Lazy<Mutex<BufReader<Maybe<StdinRaw>>>> = Lazy::new();
-    return Stdin {
-        inner: unsafe { INSTANCE.get(stdin_init).expect("cannot access stdin during shutdown") },
-    };
-
-    fn stdin_init() -> Arc<Mutex<BufReader<Maybe<StdinRaw>>>> {
-        // This must not reentrantly access `INSTANCE`
+    static INSTANCE: SyncLazy<Mutex<BufReader<Maybe<StdinRaw>>>> = SyncLazy::new(|| {
         let stdin = match stdin_raw() {
             Ok(stdin) => Maybe::Real(stdin),
             _ => Maybe::Fake,
         };
-
-        Arc::new(Mutex::new(BufReader::with_capacity(stdio::STDIN_BUF_SIZE, stdin)))
-    }
+        Mutex::new(BufReader::with_capacity(stdio::STDIN_BUF_SIZE, stdin))
+    });
+    Stdin { inner: &INSTANCE }
 }
 
 impl Stdin {
@@ -455,9 +455,8 @@ impl Read for Stdin {
     fn is_read_vectored(&self) -> bool {
         self.lock().is_read_vectored()
     }
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.lock().read_buf(buf)
     }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.lock().read_to_end(buf)
@@ -470,6 +469,12 @@ impl Read for Stdin {
     }
 }
 
+impl StdinLock<'_> {
+    pub(crate) fn as_mut_buf(&mut self) -> &mut BufReader<impl Read> {
+        &mut self.inner
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for StdinLock<'_> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
@@ -485,9 +490,8 @@ impl Read for StdinLock<'_> {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.inner.read_buf(buf)
     }
 
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
@@ -549,7 +553,7 @@ pub struct Stdout {
     // FIXME: this should be LineWriter or BufWriter depending on the state of
     //        stdout (tty or not). Note that if this is not line buffered it
     //        should also flush-on-panic or some form of flush-on-abort.
-    inner: Arc<ReentrantMutex<RefCell<LineWriter<Maybe<StdoutRaw>>>>>,
+    inner: &'static ReentrantMutex<RefCell<LineWriter<Maybe<StdoutRaw>>>>,
 }
 
 /// A locked reference to the `Stdout` handle.
@@ -612,21 +616,36 @@ pub struct StdoutLock<'a> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn stdout() -> Stdout {
-    static INSTANCE: Lazy<ReentrantMutex<RefCell<LineWriter<Maybe<StdoutRaw>>>>> = Lazy::new();
-    return Stdout {
-        inner: unsafe { INSTANCE.get(stdout_init).expect("cannot access stdout during shutdown") },
-    };
-
-    fn stdout_init() -> Arc<ReentrantMutex<RefCell<LineWriter<Maybe<StdoutRaw>>>>> {
-        // This must not reentrantly access `INSTANCE`
-        let stdout = match stdout_raw() {
-            Ok(stdout) => Maybe::Real(stdout),
-            _ => Maybe::Fake,
-        };
-        unsafe {
-            let ret = Arc::new(ReentrantMutex::new(RefCell::new(LineWriter::new(stdout))));
-            ret.init();
-            ret
+    Stdout {
+        inner: STDOUT.get_or_init_static(
+            || unsafe { ReentrantMutex::new(RefCell::new(LineWriter::new(stdout_maybe()))) },
+            |mutex| unsafe { mutex.init() },
+        ),
+    }
+}
+
+// Unlike stdin, stdout has to stay usable while the process is shutting down,
+// so it isn't dropped at exit. Instead `cleanup` flushes it and turns off its
+// buffering.
+static STDOUT: SyncOnceCell<ReentrantMutex<RefCell<LineWriter<Maybe<StdoutRaw>>>>> =
+    SyncOnceCell::new();
+
+fn stdout_maybe() -> Maybe<StdoutRaw> {
+    match stdout_raw() {
+        Ok(stdout) => Maybe::Real(stdout),
+        _ => Maybe::Fake,
+    }
+}
+
+/// Flushes stdout and disables its buffering, for use during runtime shutdown.
+pub fn cleanup() {
+    if let Some(instance) = STDOUT.get() {
+        // Replacing the line writer with one without a buffer flushes the
+        // buffered data and makes later writes go straight through. We use
+        // `try_lock` rather than `lock`, because someone might have leaked a
+        // `StdoutLock`, which would otherwise deadlock here.
+        if let Some(lock) = instance.try_lock() {
+            *lock.borrow_mut() = LineWriter::with_capacity(0, stdout_maybe());
         }
     }
 }
@@ -790,10 +809,9 @@ pub struct StderrLock<'a> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn stderr() -> Stderr {
-    // Note that unlike `stdout()` we don't use `Lazy` here which registers a
-    // destructor. Stderr is not buffered nor does the `stderr_raw` type consume
-    // any owned resources, so there's no need to run any destructors at some
-    // point in the future.
+    // Note that unlike `stdout()` we don't need to flush anything at exit.
+    // Stderr is not buffered nor does the `stderr_raw` type consume any owned
+    // resources, so it can live in a plain static.
     //
     // This has the added benefit of allowing `stderr` to be usable during
     // process shutdown as well!
@@ -1,7 +1,7 @@
 #![allow(missing_copy_implementations)]
 
 use crate::fmt;
-use crate::io::{self, BufRead, ErrorKind, Initializer, IoSlice, IoSliceMut, Read, Write};
+use crate::io::{self, BufRead, ErrorKind, IoSlice, IoSliceMut, Read, ReadBuf, Write};
 use crate::mem::MaybeUninit;
 
 /// Copies the entire contents of a reader into a writer.
@@ -45,26 +45,40 @@ where
     R: Read,
     W: Write,
 {
-    let mut buf = MaybeUninit::<[u8; super::DEFAULT_BUF_SIZE]>::uninit();
-    // FIXME(#53491): This is calling `get_mut` and `get_ref` on an uninitialized
-    // `MaybeUninit`. Revisit this once we decided whether that is valid or not.
-    // This is still technically undefined behavior due to creating a reference
-    // to uninitialized data, but within libstd we can rely on more guarantees
-    // than if this code were in an external lib.
-    unsafe {
-        reader.initializer().initialize(buf.get_mut());
+    cfg_if::cfg_if! {
+        if #[cfg(any(target_os = "linux", target_os = "android"))] {
+            crate::sys::kernel_copy::copy_spec(reader, writer)
+        } else {
+            generic_copy(reader, writer)
+        }
     }
+}
+
+/// The general read-write loop implementation of
+/// `io::copy` that is used when specializations are not available or not applicable.
+pub(crate) fn generic_copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> io::Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    let mut buf = [MaybeUninit::<u8>::uninit(); super::DEFAULT_BUF_SIZE];
+    let mut buf = ReadBuf::uninit(&mut buf);
 
     let mut written = 0;
     loop {
-        let len = match reader.read(unsafe { buf.get_mut() }) {
-            Ok(0) => return Ok(written),
-            Ok(len) => len,
+        match reader.read_buf(&mut buf) {
+            Ok(()) => {}
             Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
             Err(e) => return Err(e),
-        };
-        writer.write_all(unsafe { &buf.get_ref()[..len] })?;
-        written += len as u64;
+        }
+
+        let filled = buf.filled();
+        if filled.is_empty() {
+            return Ok(written);
+        }
+        writer.write_all(filled)?;
+        written += filled.len() as u64;
+        buf.clear();
     }
 }
 
@@ -109,8 +123,8 @@ impl Read for Empty {
     }
 
     #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, _buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        Ok(())
     }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -184,9 +198,19 @@ impl Read for Repeat {
         true
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        // SAFETY: no uninitialized bytes are written
+        for slot in unsafe { buf.unfilled_mut() } {
+            *slot = MaybeUninit::new(self.byte);
+        }
+
+        let remaining = buf.remaining();
+        // SAFETY: the entire unfilled part of `buf` has just been initialized
+        unsafe {
+            buf.assume_init(remaining);
+        }
+        buf.add_filled(remaining);
+        Ok(())
     }
 }
 
@@ -41,7 +41,7 @@ pub use core::lazy::*;
 #[unstable(feature = "once_cell", issue = "74465")]
 pub struct SyncOnceCell<T> {
     once: Once,
-    // Whether or not the value is initialized is tracked by `state_and_queue`.
+    // Whether or not the value is initialized is tracked by `once.is_completed()`.
     value: UnsafeCell<MaybeUninit<T>>,
 }
 
@@ -269,6 +269,47 @@ impl<T> SyncOnceCell<T> {
         Ok(unsafe { self.get_unchecked() })
     }
 
+    /// Internal-only API that gets the contents of a static cell,
+    /// initializing it in two steps with `f` and `g` if the cell was empty.
+    ///
+    /// `f` constructs the value, which is then moved into the cell and given
+    /// to `g` to finish its initialization. This is for values that need to
+    /// know their final address, like a `ReentrantMutex` that has to be
+    /// `init`ed in place. Other threads only get to see the value once `g`
+    /// has returned.
+    ///
+    /// # Panics
+    ///
+    /// If `f` or `g` panics, the panic is propagated to the caller, and the
+    /// cell remains uninitialized. If it is `g` that panics, the value
+    /// constructed by `f` is leaked.
+    ///
+    /// Reentrant initialization deadlocks, like it does for `get_or_init`.
+    pub(crate) fn get_or_init_static<F, G>(&'static self, f: F, g: G) -> &'static T
+    where
+        F: FnOnce() -> T,
+        G: FnOnce(&'static T),
+    {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let slot = &self.value;
+
+        // Ignore poisoning from other threads
+        // If another thread panics, then we'll be able to run our closure
+        self.once.call_once_force(|_| {
+            let value = f();
+            // Safety: the `Once` gives us unique access to the slot, and as the
+            // cell is a static the value won't move again after this.
+            let value: &'static T = unsafe { (&mut *slot.get()).write(value) };
+            g(value);
+        });
+
+        // Safety: The inner value has been initialized
+        unsafe { self.get_unchecked() }
+    }
+
     /// Consumes the `SyncOnceCell`, returning the wrapped value. Returns
     /// `None` if the cell was empty.
     ///
@@ -397,6 +438,10 @@ impl<T> Drop for SyncOnceCell<T> {
 ///
 /// This type is a thread-safe `Lazy`, and can be used in statics.
 ///
+/// Like `Lazy`, a `SyncLazy` whose initializing function panicked is poisoned,
+/// and all later accesses from any thread panic. Accessing the value again
+/// from inside the initializing function deadlocks.
+///
 /// # Examples
 ///
 /// ```
@@ -483,7 +528,7 @@ impl<T, F: FnOnce() -> T> SyncLazy<T, F> {
     pub fn force(this: &SyncLazy<T, F>) -> &T {
         this.cell.get_or_init(|| match this.init.take() {
             Some(f) => f(),
-            None => panic!("Lazy instance has previously been poisoned"),
+            None => panic!("`Lazy` instance has previously been poisoned"),
         })
     }
 }
@@ -587,6 +632,37 @@ mod tests {
         assert_eq!(ONCE_CELL.get(), Some(&92));
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri doesn't support threads
+    fn sync_once_cell_init_after_panic() {
+        static ONCE_CELL: SyncOnceCell<i32> = SyncOnceCell::new();
+
+        let res = crate::thread::spawn(|| ONCE_CELL.get_or_init(|| panic!("kaboom"))).join();
+        assert!(res.is_err());
+        assert!(ONCE_CELL.get().is_none());
+
+        assert_eq!(ONCE_CELL.get_or_init(|| 92), &92);
+    }
+
+    #[test]
+    fn sync_once_cell_get_or_init_static() {
+        static ONCE_CELL: SyncOnceCell<AtomicUsize> = SyncOnceCell::new();
+        static ADDR: AtomicUsize = AtomicUsize::new(0);
+
+        let value = ONCE_CELL.get_or_init_static(
+            || AtomicUsize::new(1),
+            |value| {
+                ADDR.store(value as *const _ as usize, SeqCst);
+                value.fetch_add(1, SeqCst);
+            },
+        );
+        assert_eq!(value.load(SeqCst), 2);
+        assert_eq!(ADDR.load(SeqCst), value as *const _ as usize);
+
+        let value = ONCE_CELL.get_or_init_static(|| panic!("kaboom"), |_| panic!("kaboom"));
+        assert_eq!(value.load(SeqCst), 2);
+    }
+
     #[test]
     fn sync_once_cell_get_mut() {
         let mut c = SyncOnceCell::new();
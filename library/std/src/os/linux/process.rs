@@ -0,0 +1,183 @@
+//! Linux-specific extensions to primitives in the `std::process` module.
+
+#![unstable(feature = "linux_pidfd", issue = "none")]
+
+use crate::io::{self, Error, ErrorKind};
+use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+use crate::process;
+use crate::sys::fd::FileDesc;
+use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+
+/// This type represents a file descriptor that refers to a process.
+///
+/// A `PidFd` can be obtained by setting the corresponding option on
+/// [`Command`] with [`create_pidfd`]. Subsequently, the created pidfd can be
+/// retrieved from the [`Child`] by calling [`pidfd`] or [`take_pidfd`].
+///
+/// Unlike a pid, a pidfd can't be recycled: it keeps referring to the same
+/// process even after that process has exited and been reaped. It becomes
+/// readable once the process exits, so it can be handed to `poll`, `epoll`
+/// and friends to wait for the child without racing with other waiters.
+///
+/// Example:
+/// ```no_run
+/// #![feature(linux_pidfd)]
+/// use std::os::linux::process::{CommandExt, ChildExt};
+/// use std::process::Command;
+///
+/// let mut child = Command::new("echo")
+///     .create_pidfd(true)
+///     .spawn()
+///     .expect("Failed to spawn child");
+///
+/// let pidfd = child
+///     .take_pidfd()
+///     .expect("Failed to retrieve pidfd");
+///
+/// // The file descriptor will be closed when `pidfd` is dropped.
+/// ```
+/// Refer to the man page of [`pidfd_open(2)`] for further details.
+///
+/// [`Command`]: ../../../../std/process/struct.Command.html
+/// [`create_pidfd`]: trait.CommandExt.html#tymethod.create_pidfd
+/// [`Child`]: ../../../../std/process/struct.Child.html
+/// [`pidfd`]: trait.ChildExt.html#tymethod.pidfd
+/// [`take_pidfd`]: trait.ChildExt.html#tymethod.take_pidfd
+/// [`pidfd_open(2)`]: https://man7.org/linux/man-pages/man2/pidfd_open.2.html
+#[derive(Debug)]
+pub struct PidFd {
+    inner: FileDesc,
+}
+
+impl AsInner<FileDesc> for PidFd {
+    fn as_inner(&self) -> &FileDesc {
+        &self.inner
+    }
+}
+
+impl FromInner<FileDesc> for PidFd {
+    fn from_inner(inner: FileDesc) -> PidFd {
+        PidFd { inner }
+    }
+}
+
+impl IntoInner<FileDesc> for PidFd {
+    fn into_inner(self) -> FileDesc {
+        self.inner
+    }
+}
+
+impl AsRawFd for PidFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.raw()
+    }
+}
+
+impl FromRawFd for PidFd {
+    unsafe fn from_raw_fd(fd: RawFd) -> PidFd {
+        PidFd { inner: FileDesc::new(fd) }
+    }
+}
+
+impl IntoRawFd for PidFd {
+    fn into_raw_fd(self) -> RawFd {
+        self.inner.into_raw()
+    }
+}
+
+fn pidfd_unavailable() -> Error {
+    Error::new(ErrorKind::Other, "no pidfd was created for this child")
+}
+
+mod private {
+    /// This trait being unreachable from outside the crate prevents outside
+    /// implementations of the extension traits below, so that methods can be
+    /// added to them later without breaking anyone.
+    pub trait Sealed {}
+
+    impl Sealed for crate::process::Child {}
+    impl Sealed for crate::process::Command {}
+}
+
+/// Linux-specific extensions to [`Child`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard
+/// library.
+///
+/// [`Child`]: ../../../../std/process/struct.Child.html
+pub trait ChildExt: private::Sealed {
+    /// Obtains a reference to the [`PidFd`] created for this [`Child`], if available.
+    ///
+    /// A pidfd will only be available if its creation was requested with
+    /// [`create_pidfd`] when the corresponding [`Command`] was created, and
+    /// the kernel supports `clone3(CLONE_PIDFD)` or `pidfd_open` (Linux 5.3
+    /// and later).
+    ///
+    /// Even if requested, a pidfd may not be available; an error is returned
+    /// in that case.
+    ///
+    /// [`PidFd`]: struct.PidFd.html
+    /// [`Command`]: ../../../../std/process/struct.Command.html
+    /// [`create_pidfd`]: trait.CommandExt.html#tymethod.create_pidfd
+    /// [`Child`]: ../../../../std/process/struct.Child.html
+    fn pidfd(&self) -> io::Result<&PidFd>;
+
+    /// Takes ownership of the [`PidFd`] created for this [`Child`], if available.
+    ///
+    /// A pidfd is only available under the same conditions as for
+    /// [`pidfd`], and can only be taken once.
+    ///
+    /// [`PidFd`]: struct.PidFd.html
+    /// [`pidfd`]: #tymethod.pidfd
+    /// [`Child`]: ../../../../std/process/struct.Child.html
+    fn take_pidfd(&mut self) -> io::Result<PidFd>;
+}
+
+/// Linux-specific extensions to [`Command`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard
+/// library.
+///
+/// [`Command`]: ../../../../std/process/struct.Command.html
+pub trait CommandExt: private::Sealed {
+    /// Sets whether a [`PidFd`] should be created for the [`Child`] spawned
+    /// by this [`Command`]. By default, no pidfd will be created.
+    ///
+    /// The pidfd can be retrieved from the child with [`pidfd`] or [`take_pidfd`].
+    ///
+    /// The pidfd is created atomically with the child by `clone3` where
+    /// possible, and otherwise with `pidfd_open` right after `fork`. If the
+    /// kernel supports neither (i.e. before Linux 5.3) the child is still
+    /// spawned, but [`pidfd`] will return an error.
+    ///
+    /// If `SIGCHLD` is ignored (set to `SIG_IGN`) in the parent, the kernel
+    /// reaps exited children automatically. When the pidfd has to be created
+    /// with `pidfd_open`, a child which exits immediately may then already
+    /// be gone, and its pid may have been reused by an unrelated process
+    /// which the pidfd would refer to instead. Don't ignore `SIGCHLD` when
+    /// relying on pidfds.
+    ///
+    /// [`PidFd`]: struct.PidFd.html
+    /// [`Command`]: ../../../../std/process/struct.Command.html
+    /// [`Child`]: ../../../../std/process/struct.Child.html
+    /// [`pidfd`]: trait.ChildExt.html#tymethod.pidfd
+    /// [`take_pidfd`]: trait.ChildExt.html#tymethod.take_pidfd
+    fn create_pidfd(&mut self, val: bool) -> &mut process::Command;
+}
+
+impl ChildExt for process::Child {
+    fn pidfd(&self) -> io::Result<&PidFd> {
+        self.as_inner().pidfd().ok_or_else(pidfd_unavailable)
+    }
+
+    fn take_pidfd(&mut self) -> io::Result<PidFd> {
+        self.as_inner_mut().take_pidfd().ok_or_else(pidfd_unavailable)
+    }
+}
+
+impl CommandExt for process::Command {
+    fn create_pidfd(&mut self, val: bool) -> &mut process::Command {
+        self.as_inner_mut().create_pidfd(val);
+        self
+    }
+}
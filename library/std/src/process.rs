@@ -111,13 +111,17 @@ use crate::io::prelude::*;
 use crate::ffi::OsStr;
 use crate::fmt;
 use crate::fs;
-use crate::io::{self, Initializer, IoSlice, IoSliceMut};
+use crate::io::{self, IoSlice, IoSliceMut, ReadBuf};
+use crate::num::NonZeroI32;
 use crate::path::Path;
 use crate::str;
 use crate::sys::pipe::{read2, AnonPipe};
 use crate::sys::process as imp;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 
+#[unstable(feature = "command_access", issue = "none")]
+pub use crate::sys_common::process::CommandEnvs;
+
 /// Representation of a running or exited child process.
 ///
 /// This structure is used to represent and manage child processes. A child
@@ -191,6 +195,12 @@ impl AsInner<imp::Process> for Child {
     }
 }
 
+impl AsInnerMut<imp::Process> for Child {
+    fn as_inner_mut(&mut self) -> &mut imp::Process {
+        &mut self.handle
+    }
+}
+
 impl FromInner<(imp::Process, imp::StdioPipes)> for Child {
     fn from_inner((handle, io): (imp::Process, imp::StdioPipes)) -> Child {
         Child {
@@ -309,9 +319,8 @@ impl Read for ChildStdout {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.inner.read_buf(buf)
     }
 }
 
@@ -370,9 +379,8 @@ impl Read for ChildStderr {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.inner.read_buf(buf)
     }
 }
 
@@ -863,6 +871,134 @@ impl Command {
             .map(Child::from_inner)
             .and_then(|mut p| p.wait())
     }
+
+    /// Returns the path to the program that was given to [`Command::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(command_access)]
+    /// use std::process::Command;
+    ///
+    /// let cmd = Command::new("echo");
+    /// assert_eq!(cmd.get_program(), "echo");
+    /// ```
+    ///
+    /// [`Command::new`]: #method.new
+    #[unstable(feature = "command_access", issue = "none")]
+    pub fn get_program(&self) -> &OsStr {
+        self.inner.get_program()
+    }
+
+    /// Returns an iterator of the arguments that will be passed to the program.
+    ///
+    /// This does not include the path to the program as the first argument;
+    /// it only includes the arguments specified with [`Command::arg`] and
+    /// [`Command::args`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(command_access)]
+    /// use std::ffi::OsStr;
+    /// use std::process::Command;
+    ///
+    /// let mut cmd = Command::new("echo");
+    /// cmd.arg("first").arg("second");
+    /// let args: Vec<&OsStr> = cmd.get_args().collect();
+    /// assert_eq!(args, &["first", "second"]);
+    /// ```
+    ///
+    /// [`Command::arg`]: #method.arg
+    /// [`Command::args`]: #method.args
+    #[unstable(feature = "command_access", issue = "none")]
+    pub fn get_args(&self) -> CommandArgs<'_> {
+        CommandArgs { inner: self.inner.get_args() }
+    }
+
+    /// Returns an iterator of the environment variables that will be set when
+    /// the process is spawned.
+    ///
+    /// Each element is a tuple `(&OsStr, Option<&OsStr>)`, where the first
+    /// value is the key, and the second is the value, which is [`None`] if
+    /// the environment variable is to be explicitly removed.
+    ///
+    /// This only includes environment variables explicitly set with
+    /// [`Command::env`], [`Command::envs`], and [`Command::env_remove`]. It
+    /// does not include environment variables that will be inherited by the
+    /// child process. Whether the inherited environment is cleared first is
+    /// reported by [`Command::get_env_clear`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(command_access)]
+    /// use std::ffi::OsStr;
+    /// use std::process::Command;
+    ///
+    /// let mut cmd = Command::new("ls");
+    /// cmd.env("TERM", "dumb").env_remove("TZ");
+    /// let envs: Vec<(&OsStr, Option<&OsStr>)> = cmd.get_envs().collect();
+    /// assert_eq!(envs, &[
+    ///     (OsStr::new("TERM"), Some(OsStr::new("dumb"))),
+    ///     (OsStr::new("TZ"), None)
+    /// ]);
+    /// ```
+    ///
+    /// [`None`]: ../option/enum.Option.html#variant.None
+    /// [`Command::env`]: #method.env
+    /// [`Command::envs`]: #method.envs
+    /// [`Command::env_remove`]: #method.env_remove
+    /// [`Command::get_env_clear`]: #method.get_env_clear
+    #[unstable(feature = "command_access", issue = "none")]
+    pub fn get_envs(&self) -> CommandEnvs<'_> {
+        self.inner.get_envs()
+    }
+
+    /// Returns whether [`Command::env_clear`] was called, i.e. whether the
+    /// child process will start from an empty environment instead of
+    /// inheriting the parent's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(command_access)]
+    /// use std::process::Command;
+    ///
+    /// let mut cmd = Command::new("ls");
+    /// assert!(!cmd.get_env_clear());
+    /// cmd.env_clear();
+    /// assert!(cmd.get_env_clear());
+    /// ```
+    ///
+    /// [`Command::env_clear`]: #method.env_clear
+    #[unstable(feature = "command_access", issue = "none")]
+    pub fn get_env_clear(&self) -> bool {
+        self.inner.get_env_clear()
+    }
+
+    /// Returns the working directory for the child process.
+    ///
+    /// This returns [`None`] if the working directory will not be changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(command_access)]
+    /// use std::path::Path;
+    /// use std::process::Command;
+    ///
+    /// let mut cmd = Command::new("ls");
+    /// assert_eq!(cmd.get_current_dir(), None);
+    /// cmd.current_dir("/bin");
+    /// assert_eq!(cmd.get_current_dir(), Some(Path::new("/bin")));
+    /// ```
+    ///
+    /// [`None`]: ../option/enum.Option.html#variant.None
+    #[unstable(feature = "command_access", issue = "none")]
+    pub fn get_current_dir(&self) -> Option<&Path> {
+        self.inner.get_current_dir()
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -870,11 +1006,46 @@ impl fmt::Debug for Command {
     /// Format the program and arguments of a Command for display. Any
     /// non-utf8 data is lossily converted using the utf8 replacement
     /// character.
+    ///
+    /// On Unix, the output is a command line that a POSIX shell would run
+    /// the same way: working directory and environment changes come first,
+    /// and every word is quoted as needed. The alternate format (`{:#?}`)
+    /// shows all attributes of the command instead.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.inner.fmt(f)
     }
 }
 
+/// An iterator over the command arguments.
+///
+/// This struct is created by [`Command::get_args`]. See its documentation for
+/// more.
+///
+/// [`Command::get_args`]: struct.Command.html#method.get_args
+#[unstable(feature = "command_access", issue = "none")]
+#[derive(Debug)]
+pub struct CommandArgs<'a> {
+    inner: imp::CommandArgs<'a>,
+}
+
+#[unstable(feature = "command_access", issue = "none")]
+impl<'a> Iterator for CommandArgs<'a> {
+    type Item = &'a OsStr;
+    fn next(&mut self) -> Option<&'a OsStr> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "command_access", issue = "none")]
+impl<'a> ExactSizeIterator for CommandArgs<'a> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
 impl AsInner<imp::Command> for Command {
     fn as_inner(&self) -> &imp::Command {
         &self.inner
@@ -1264,6 +1435,32 @@ impl ExitStatus {
     pub fn code(&self) -> Option<i32> {
         self.0.code()
     }
+
+    /// Was termination successful? Returns a `Result`.
+    ///
+    /// This makes it easy to propagate an unsuccessful exit status with `?`,
+    /// or to turn it into an error with a useful message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(exit_status_error)]
+    /// # if cfg!(unix) {
+    /// use std::process::Command;
+    ///
+    /// let status = Command::new("ls")
+    ///                      .arg("/dev/nonexistent")
+    ///                      .status()
+    ///                      .expect("ls could not be executed");
+    ///
+    /// println!("ls: {}", status);
+    /// status.exit_ok().expect_err("/dev/nonexistent could be listed!");
+    /// # } // cfg!(unix)
+    /// ```
+    #[unstable(feature = "exit_status_error", issue = "none")]
+    pub fn exit_ok(&self) -> Result<(), ExitStatusError> {
+        if self.success() { Ok(()) } else { Err(ExitStatusError(*self)) }
+    }
 }
 
 impl AsInner<imp::ExitStatus> for ExitStatus {
@@ -1285,6 +1482,116 @@ impl fmt::Display for ExitStatus {
     }
 }
 
+/// Describes the result of a process after it has failed.
+///
+/// Produced by the [`exit_ok`] method on [`ExitStatus`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(exit_status_error)]
+/// # if cfg!(unix) {
+/// use std::process::{Command, ExitStatusError};
+///
+/// fn run(cmd: &str) -> Result<(), ExitStatusError> {
+///     Command::new(cmd).status().unwrap().exit_ok()?;
+///     Ok(())
+/// }
+///
+/// run("true").unwrap();
+/// run("false").unwrap_err();
+/// # } // cfg!(unix)
+/// ```
+///
+/// [`exit_ok`]: struct.ExitStatus.html#method.exit_ok
+/// [`ExitStatus`]: struct.ExitStatus.html
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[unstable(feature = "exit_status_error", issue = "none")]
+// The wrapped status is never successful.
+pub struct ExitStatusError(ExitStatus);
+
+#[unstable(feature = "exit_status_error", issue = "none")]
+impl ExitStatusError {
+    /// Reports the exit code, if applicable, from an `ExitStatusError`.
+    ///
+    /// In Unix terms the return value is the **exit status**: the value
+    /// passed to `exit`, if the process finished by calling `exit`. Note
+    /// that on Unix the exit status is truncated to 8 bits, and that values
+    /// that didn't come from a program's call to `exit` may be invented by
+    /// the runtime system (often, for example, 255, 254, 127 or 126).
+    ///
+    /// On Unix, this will return `None` if the process was terminated by a
+    /// signal. If you want to handle such situations specially, consider
+    /// using methods from [`ExitStatusExt`].
+    ///
+    /// If the process finished by calling `exit` with a nonzero value, this
+    /// will return that exit status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(exit_status_error)]
+    /// # if cfg!(unix) {
+    /// use std::process::Command;
+    ///
+    /// let bad = Command::new("false").status().unwrap().exit_ok().unwrap_err();
+    /// assert_eq!(bad.code(), Some(1));
+    /// # } // cfg!(unix)
+    /// ```
+    ///
+    /// [`ExitStatusExt`]: ../os/unix/process/trait.ExitStatusExt.html
+    pub fn code(&self) -> Option<i32> {
+        self.code_nonzero().map(Into::into)
+    }
+
+    /// Reports the exit code, if applicable, from an `ExitStatusError`, as
+    /// a `NonZeroI32`.
+    ///
+    /// This is exactly like [`code()`](#method.code), except that it returns
+    /// a `NonZeroI32`: an unsuccessful status never has an exit code of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(exit_status_error)]
+    /// # if cfg!(unix) {
+    /// use std::convert::TryFrom;
+    /// use std::num::NonZeroI32;
+    /// use std::process::Command;
+    ///
+    /// let bad = Command::new("false").status().unwrap().exit_ok().unwrap_err();
+    /// assert_eq!(bad.code_nonzero().unwrap(), NonZeroI32::try_from(1).unwrap());
+    /// # } // cfg!(unix)
+    /// ```
+    pub fn code_nonzero(&self) -> Option<NonZeroI32> {
+        self.0.code().map(|code| {
+            NonZeroI32::new(code).expect("an unsuccessful exit status can't have an exit code of 0")
+        })
+    }
+
+    /// Converts an `ExitStatusError` (back) to an `ExitStatus`.
+    pub fn into_status(&self) -> ExitStatus {
+        self.0
+    }
+}
+
+#[unstable(feature = "exit_status_error", issue = "none")]
+impl From<ExitStatusError> for ExitStatus {
+    fn from(error: ExitStatusError) -> Self {
+        error.0
+    }
+}
+
+#[unstable(feature = "exit_status_error", issue = "none")]
+impl fmt::Display for ExitStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "process exited unsuccessfully: {}", self.0)
+    }
+}
+
+#[unstable(feature = "exit_status_error", issue = "none")]
+impl crate::error::Error for ExitStatusError {}
+
 /// This type represents the status code a process can return to its
 /// parent under normal termination.
 ///
@@ -1325,6 +1632,29 @@ impl ExitCode {
     pub const FAILURE: ExitCode = ExitCode(imp::ExitCode::FAILURE);
 }
 
+#[unstable(feature = "process_exitcode_placeholder", issue = "48711")]
+impl From<u8> for ExitCode {
+    /// Constructs an `ExitCode` from an arbitrary `u8` value.
+    ///
+    /// This lets `main` exit with a precise status without calling
+    /// [`process::exit`], so destructors still run:
+    ///
+    /// ```
+    /// #![feature(process_exitcode_placeholder)]
+    /// use std::process::ExitCode;
+    ///
+    /// fn main() -> ExitCode {
+    ///     let missing_files = 0u8;
+    ///     ExitCode::from(missing_files)
+    /// }
+    /// ```
+    ///
+    /// [`process::exit`]: fn.exit.html
+    fn from(code: u8) -> Self {
+        ExitCode(imp::ExitCode::from(code))
+    }
+}
+
 impl Child {
     /// Forces the child process to exit. If the child has already exited, an [`InvalidInput`]
     /// error is returned.
@@ -1695,6 +2025,16 @@ impl<E: fmt::Debug> Termination for Result<!, E> {
     }
 }
 
+#[unstable(feature = "termination_trait_lib", issue = "43301")]
+impl<E: fmt::Debug> Termination for Result<ExitCode, E> {
+    fn report(self) -> i32 {
+        match self {
+            Ok(code) => code.report(),
+            Err(err) => Err::<!, _>(err).report(),
+        }
+    }
+}
+
 #[unstable(feature = "termination_trait_lib", issue = "43301")]
 impl Termination for ExitCode {
     #[inline]
@@ -1764,6 +2104,92 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(all(unix, not(target_os = "vxworks")))]
+    #[cfg_attr(any(target_os = "android", target_os = "fuchsia"), ignore)]
+    fn signal_sent_right() {
+        use crate::os::unix::process::{ChildExt, ExitStatusExt};
+
+        let mut p =
+            Command::new("/bin/sh").arg("-c").arg("read a").stdin(Stdio::piped()).spawn().unwrap();
+        p.signal(libc::SIGTERM).unwrap();
+        assert_eq!(p.wait().unwrap().signal(), Some(libc::SIGTERM));
+        assert_eq!(p.signal(libc::SIGTERM).unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    #[cfg(all(unix, not(target_os = "vxworks")))]
+    #[cfg_attr(any(target_os = "android", target_os = "fuchsia"), ignore)]
+    fn process_group_works() {
+        use crate::os::unix::process::CommandGroupExt;
+
+        let mut p = Command::new("/bin/sh")
+            .arg("-c")
+            .arg("read a")
+            .stdin(Stdio::piped())
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let pgid = unsafe { libc::getpgid(p.id() as libc::pid_t) };
+        assert_eq!(pgid, p.id() as libc::pid_t);
+        assert_ne!(pgid, unsafe { libc::getpgrp() });
+        p.kill().unwrap();
+        p.wait().unwrap();
+    }
+
+    #[test]
+    #[cfg(all(unix, not(target_os = "vxworks")))]
+    #[cfg_attr(any(target_os = "android", target_os = "fuchsia"), ignore)]
+    fn setsid_works() {
+        use crate::os::unix::process::CommandGroupExt;
+
+        let mut p = Command::new("/bin/sh")
+            .arg("-c")
+            .arg("read a")
+            .stdin(Stdio::piped())
+            .setsid(true)
+            .spawn()
+            .unwrap();
+        let sid = unsafe { libc::getsid(p.id() as libc::pid_t) };
+        assert_eq!(sid, p.id() as libc::pid_t);
+        p.kill().unwrap();
+        p.wait().unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn pidfd_works() {
+        use crate::os::linux::process::{ChildExt, CommandExt};
+        use crate::os::unix::io::AsRawFd;
+        use crate::os::unix::process::{ChildExt as _, ExitStatusExt};
+
+        let mut p = Command::new("/bin/sh")
+            .arg("-c")
+            .arg("read a")
+            .stdin(Stdio::piped())
+            .create_pidfd(true)
+            .spawn()
+            .unwrap();
+
+        // Kernels older than 5.3 can't create a pidfd at all.
+        if p.pidfd().is_err() {
+            p.kill().unwrap();
+            p.wait().unwrap();
+            return;
+        }
+        assert!(p.pidfd().unwrap().as_raw_fd() >= 0);
+        p.signal(libc::SIGTERM).unwrap();
+        assert_eq!(p.wait().unwrap().signal(), Some(libc::SIGTERM));
+
+        let pidfd = p.take_pidfd().unwrap();
+        assert!(p.pidfd().is_err());
+        drop(pidfd);
+
+        let mut p = Command::new("true").spawn().unwrap();
+        assert!(p.pidfd().is_err());
+        p.wait().unwrap();
+    }
+
     pub fn run_output(mut cmd: Command) -> String {
         let p = cmd.spawn();
         assert!(p.is_ok());
@@ -2109,4 +2535,111 @@ mod tests {
         fn take_send_sync_type<T: Send + Sync>(_: T) {}
         take_send_sync_type(Command::new(""))
     }
+
+    #[test]
+    #[cfg(unix)]
+    #[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+    fn exit_ok_works() {
+        assert!(Command::new("true").status().unwrap().exit_ok().is_ok());
+
+        let err = Command::new("false").status().unwrap().exit_ok().unwrap_err();
+        assert_eq!(err.code(), Some(1));
+        assert_eq!(err.code_nonzero().map(|code| code.get()), Some(1));
+        assert!(!err.into_status().success());
+        assert_eq!(err.to_string(), "process exited unsuccessfully: exit code: 1");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn exit_status_details() {
+        use super::ExitStatus;
+        use crate::os::unix::process::ExitStatusExt;
+
+        // Raw wait statuses as encoded by Linux.
+        let exited = ExitStatus::from_raw(3 << 8);
+        assert_eq!((exited.code(), exited.signal()), (Some(3), None));
+        assert!(!exited.core_dumped());
+        assert_eq!(exited.stopped_signal(), None);
+
+        let killed = ExitStatus::from_raw(libc::SIGSEGV | 0x80);
+        assert_eq!((killed.code(), killed.signal()), (None, Some(libc::SIGSEGV)));
+        assert!(killed.core_dumped());
+        assert_eq!(killed.to_string(), format!("signal: {} (core dumped)", libc::SIGSEGV));
+
+        let stopped = ExitStatus::from_raw((libc::SIGSTOP << 8) | 0x7f);
+        assert_eq!((stopped.code(), stopped.signal()), (None, None));
+        assert_eq!(stopped.stopped_signal(), Some(libc::SIGSTOP));
+        assert!(stopped.exit_ok().unwrap_err().code().is_none());
+    }
+
+    #[test]
+    fn exit_code_from_u8() {
+        use super::{ExitCode, Termination};
+
+        assert_eq!(ExitCode::from(42).report(), 42);
+        assert_eq!(Ok::<_, ()>(ExitCode::from(3)).report(), 3);
+        assert_eq!(Err::<ExitCode, _>("oops").report(), ExitCode::FAILURE.report());
+    }
+
+    #[test]
+    fn command_getters() {
+        use crate::ffi::OsStr;
+        use crate::path::Path;
+
+        let mut cmd = Command::new("echo");
+        assert_eq!(cmd.get_program(), "echo");
+        assert_eq!(cmd.get_args().len(), 0);
+        assert_eq!(cmd.get_envs().len(), 0);
+        assert!(!cmd.get_env_clear());
+        assert_eq!(cmd.get_current_dir(), None);
+
+        cmd.args(&["a", "b c"]).env("FOO", "bar").env_remove("BAZ").current_dir("dir");
+        assert_eq!(cmd.get_args().collect::<Vec<_>>(), &["a", "b c"]);
+        assert_eq!(
+            cmd.get_envs().collect::<Vec<_>>(),
+            &[(OsStr::new("BAZ"), None), (OsStr::new("FOO"), Some(OsStr::new("bar")))]
+        );
+        assert_eq!(cmd.get_current_dir(), Some(Path::new("dir")));
+
+        // Clearing the environment forgets about removals, but keeps later sets.
+        cmd.env_clear().env("QUX", "1").env_remove("BAZ");
+        assert!(cmd.get_env_clear());
+        let envs: Vec<_> = cmd.get_envs().collect();
+        assert_eq!(envs, &[(OsStr::new("QUX"), Some(OsStr::new("1")))]);
+    }
+
+    #[test]
+    #[cfg(all(unix, not(target_os = "vxworks")))]
+    fn command_debug_is_shell_quoted() {
+        let mut cmd = Command::new("echo");
+        cmd.arg("plain-arg_1.0").arg("two words").arg("it's").arg("").arg("$HOME");
+        assert_eq!(format!("{:?}", cmd), r#"echo plain-arg_1.0 'two words' 'it'\''s' '' '$HOME'"#);
+
+        let mut cmd = Command::new("ls");
+        cmd.arg("-l").env("FOO", "a b").env_remove("BAR").current_dir("/some dir");
+        assert_eq!(format!("{:?}", cmd), "cd '/some dir' && env -u BAR FOO='a b' ls -l");
+
+        let mut cmd = Command::new("true");
+        cmd.env_clear().env("A", "1");
+        assert_eq!(format!("{:?}", cmd), "env -i A=1 true");
+
+        let mut cmd = Command::new("true");
+        cmd.env("A B", "1").env_remove("C'D");
+        assert_eq!(format!("{:?}", cmd), r#"env -u 'C'\''D' 'A B=1' true"#);
+
+        let mut cmd = Command::new("true");
+        cmd.env("A", "1").env("B", "x y");
+        assert_eq!(format!("{:?}", cmd), "A=1 B='x y' true");
+    }
+
+    #[test]
+    #[cfg(all(unix, not(target_os = "vxworks")))]
+    fn command_debug_quotes_non_utf8_bytes() {
+        use crate::ffi::OsStr;
+        use crate::os::unix::ffi::OsStrExt;
+
+        let mut cmd = Command::new("echo");
+        cmd.arg(OsStr::from_bytes(b"\xff'a\\b c\n"));
+        assert_eq!(format!("{:?}", cmd), r"echo $'\xff\'a\\b c\x0a'");
+    }
 }
@@ -172,6 +172,8 @@ pub use crate::sys_common::poison::{LockResult, PoisonError, TryLockError, TryLo
 
 pub mod mpsc;
 
+pub(crate) mod mpmc;
+
 mod barrier;
 mod condvar;
 mod mutex;
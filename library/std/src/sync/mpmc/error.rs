@@ -0,0 +1,13 @@
+pub use crate::sync::mpsc::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+
+/// An error returned from the blocking send operations of the bounded flavors.
+///
+/// This is the sending counterpart of `RecvTimeoutError`; the message that could not be sent is
+/// handed back in both variants.
+pub enum SendTimeoutError<T> {
+    /// The message could not be sent before the deadline was reached.
+    Timeout(T),
+
+    /// The message could not be sent because the channel is disconnected.
+    Disconnected(T),
+}
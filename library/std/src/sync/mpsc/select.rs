@@ -0,0 +1,409 @@
+//! Waiting on several channel operations at once.
+
+use crate::error;
+use crate::fmt;
+use crate::marker::PhantomData;
+use crate::mem;
+use crate::sync::mpmc::{self, SelectHandle, Timeout, Token};
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+use super::{Receiver, RecvError, SendError, SyncSender};
+
+/// Waits on a set of channel operations.
+///
+/// Receive operations are added with [`recv`] and synchronous send operations
+/// with [`send`]; each call returns the index of the operation. Sends on an
+/// asynchronous [`Sender`] never block, so they do not take part in selection.
+///
+/// Once an operation has been selected it must be completed by passing the
+/// same channel half to [`SelectedOperation::recv`] or
+/// [`SelectedOperation::send`]. If several operations are ready at the same
+/// time, one of them is chosen at random.
+///
+/// An operation on a disconnected channel is considered ready, and completing
+/// it returns an error.
+///
+/// [`recv`]: struct.Select.html#method.recv
+/// [`send`]: struct.Select.html#method.send
+/// [`Sender`]: struct.Sender.html
+/// [`SelectedOperation::recv`]: struct.SelectedOperation.html#method.recv
+/// [`SelectedOperation::send`]: struct.SelectedOperation.html#method.send
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpsc_select)]
+/// use std::sync::mpsc::{channel, Select};
+/// use std::thread;
+///
+/// let (tx1, rx1) = channel();
+/// let (tx2, rx2) = channel::<i32>();
+///
+/// thread::spawn(move || tx1.send(10).unwrap());
+///
+/// let mut sel = Select::new();
+/// let oper1 = sel.recv(&rx1);
+/// let oper2 = sel.recv(&rx2);
+///
+/// let oper = sel.select();
+/// match oper.index() {
+///     i if i == oper1 => assert_eq!(oper.recv(&rx1), Ok(10)),
+///     i if i == oper2 => panic!("nothing was sent on rx2"),
+///     _ => unreachable!(),
+/// }
+/// # drop(tx2);
+/// ```
+#[unstable(feature = "mpsc_select", issue = "none")]
+pub struct Select<'a> {
+    /// The registered operations: a handle, the index of the operation and
+    /// the address of the channel half it was registered with.
+    handles: Vec<(&'a dyn SelectHandle, usize, *const u8)>,
+
+    /// The index that will be assigned to the next operation.
+    next_index: usize,
+}
+
+impl<'a> Select<'a> {
+    /// Creates an empty list of channel operations.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn new() -> Select<'a> {
+        Select { handles: Vec::with_capacity(4), next_index: 0 }
+    }
+
+    /// Adds a send operation on a [`SyncSender`] and returns its index.
+    ///
+    /// [`SyncSender`]: struct.SyncSender.html
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn send<T>(&mut self, s: &'a SyncSender<T>) -> usize {
+        let ptr = s as *const SyncSender<T> as *const u8;
+        self.add(&s.inner, ptr)
+    }
+
+    /// Adds a receive operation on a [`Receiver`] and returns its index.
+    ///
+    /// [`Receiver`]: struct.Receiver.html
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn recv<T>(&mut self, r: &'a Receiver<T>) -> usize {
+        let ptr = r as *const Receiver<T> as *const u8;
+        self.add(&r.inner, ptr)
+    }
+
+    fn add(&mut self, handle: &'a dyn SelectHandle, ptr: *const u8) -> usize {
+        let i = self.next_index;
+        self.handles.push((handle, i, ptr));
+        self.next_index += 1;
+        i
+    }
+
+    /// Removes a previously added operation.
+    ///
+    /// This is useful when an operation has been selected because its channel
+    /// got disconnected, and it should not be selected again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not the index of an operation in this list.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn remove(&mut self, index: usize) {
+        assert!(index < self.next_index, "index out of bounds; {} >= {}", index, self.next_index);
+
+        let i = self
+            .handles
+            .iter()
+            .position(|&(_, i, _)| i == index)
+            .expect("no operation with this index");
+        self.handles.swap_remove(i);
+    }
+
+    /// Attempts to select one of the operations without blocking.
+    ///
+    /// Returns an error if none of the operations is ready.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn try_select(&mut self) -> Result<SelectedOperation<'a>, TrySelectError> {
+        self.run(Timeout::Now).ok_or(TrySelectError { _priv: () })
+    }
+
+    /// Blocks until one of the operations becomes ready and selects it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operations have been added, since this would block
+    /// forever.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn select(&mut self) -> SelectedOperation<'a> {
+        assert!(!self.handles.is_empty(), "no operations have been added to `Select`");
+        self.run(Timeout::Never).unwrap()
+    }
+
+    /// Blocks for at most `timeout` until one of the operations becomes ready
+    /// and selects it.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn select_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.select_deadline(deadline),
+            // So far in the future that it's practically the same as waiting indefinitely.
+            None => Ok(self.select()),
+        }
+    }
+
+    /// Blocks until `deadline` for one of the operations to become ready and
+    /// selects it.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn select_deadline(
+        &mut self,
+        deadline: Instant,
+    ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
+        self.run(Timeout::At(deadline)).ok_or(SelectTimeoutError { _priv: () })
+    }
+
+    fn run(&mut self, timeout: Timeout) -> Option<SelectedOperation<'a>> {
+        mpmc::run_select(&mut self.handles, timeout).map(|(token, index, ptr)| {
+            SelectedOperation { token, index, ptr, _marker: PhantomData }
+        })
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl<'a> Clone for Select<'a> {
+    fn clone(&self) -> Select<'a> {
+        Select { handles: self.handles.clone(), next_index: self.next_index }
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl<'a> Default for Select<'a> {
+    fn default() -> Select<'a> {
+        Select::new()
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Select { .. }")
+    }
+}
+
+/// An operation chosen by [`Select`] that still has to be completed.
+///
+/// The operation must be completed with [`recv`] or [`send`], passing the
+/// channel half that was registered for it. Dropping a `SelectedOperation`
+/// without completing it panics.
+///
+/// [`Select`]: struct.Select.html
+/// [`recv`]: struct.SelectedOperation.html#method.recv
+/// [`send`]: struct.SelectedOperation.html#method.send
+#[unstable(feature = "mpsc_select", issue = "none")]
+#[must_use]
+pub struct SelectedOperation<'a> {
+    /// State that the channel needs to complete the operation.
+    token: Token,
+
+    /// The index of the selected operation.
+    index: usize,
+
+    /// The address of the selected channel half.
+    ptr: *const u8,
+
+    /// Ties the operation to the lifetime of the channel halves in `Select`.
+    _marker: PhantomData<&'a ()>,
+}
+
+impl SelectedOperation<'_> {
+    /// Returns the index of the selected operation.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Completes the send operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not the sender that was selected.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn send<T>(mut self, s: &SyncSender<T>, msg: T) -> Result<(), SendError<T>> {
+        assert!(
+            s as *const SyncSender<T> as *const u8 == self.ptr,
+            "passed a sender that wasn't selected",
+        );
+        let res = unsafe { s.inner.write(&mut self.token, msg) };
+        mem::forget(self);
+        res.map_err(SendError)
+    }
+
+    /// Completes the receive operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not the receiver that was selected.
+    #[unstable(feature = "mpsc_select", issue = "none")]
+    pub fn recv<T>(mut self, r: &Receiver<T>) -> Result<T, RecvError> {
+        assert!(
+            r as *const Receiver<T> as *const u8 == self.ptr,
+            "passed a receiver that wasn't selected",
+        );
+        let res = unsafe { r.inner.read(&mut self.token) };
+        mem::forget(self);
+        res.map_err(|_| RecvError)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Debug for SelectedOperation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectedOperation").field("index", &self.index).finish()
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl Drop for SelectedOperation<'_> {
+    fn drop(&mut self) {
+        // Don't turn a failed assertion in `send` or `recv` into a double panic.
+        if !thread::panicking() {
+            panic!("dropped `SelectedOperation` without completing the operation");
+        }
+    }
+}
+
+/// An error returned from [`Select::try_select`] when no operation is ready.
+///
+/// [`Select::try_select`]: struct.Select.html#method.try_select
+#[unstable(feature = "mpsc_select", issue = "none")]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TrySelectError {
+    _priv: (),
+}
+
+/// An error returned from [`Select::select_timeout`] and
+/// [`Select::select_deadline`] when no operation became ready in time.
+///
+/// [`Select::select_timeout`]: struct.Select.html#method.select_timeout
+/// [`Select::select_deadline`]: struct.Select.html#method.select_deadline
+#[unstable(feature = "mpsc_select", issue = "none")]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SelectTimeoutError {
+    _priv: (),
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Display for TrySelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "all operations in select would block".fmt(f)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl error::Error for TrySelectError {}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl fmt::Display for SelectTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "timed out waiting on select".fmt(f)
+    }
+}
+
+#[unstable(feature = "mpsc_select", issue = "none")]
+impl error::Error for SelectTimeoutError {}
+
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests {
+    use super::*;
+    use crate::sync::mpsc::{channel, sync_channel};
+    use crate::thread;
+
+    #[test]
+    fn select_ready_receiver() {
+        let (_tx1, rx1) = channel::<i32>();
+        let (tx2, rx2) = channel::<i32>();
+        tx2.send(2).unwrap();
+
+        let mut sel = Select::new();
+        let _oper1 = sel.recv(&rx1);
+        let oper2 = sel.recv(&rx2);
+
+        let oper = sel.select();
+        assert_eq!(oper.index(), oper2);
+        assert_eq!(oper.recv(&rx2), Ok(2));
+    }
+
+    #[test]
+    fn select_blocks_until_sent() {
+        let (tx1, rx1) = channel::<i32>();
+        let (tx2, rx2) = sync_channel::<i32>(1);
+        let t = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            tx2.send(2).unwrap();
+            tx1
+        });
+
+        let mut sel = Select::new();
+        let _oper1 = sel.recv(&rx1);
+        let oper2 = sel.recv(&rx2);
+
+        let oper = sel.select();
+        assert_eq!(oper.index(), oper2);
+        assert_eq!(oper.recv(&rx2), Ok(2));
+        drop(t.join().unwrap());
+    }
+
+    #[test]
+    fn select_send_rendezvous() {
+        let (tx, rx) = sync_channel::<i32>(0);
+        let t = thread::spawn(move || rx.recv().unwrap());
+
+        let mut sel = Select::new();
+        let oper1 = sel.send(&tx);
+        let oper = sel.select();
+        assert_eq!(oper.index(), oper1);
+        oper.send(&tx, 7).unwrap();
+        assert_eq!(t.join().unwrap(), 7);
+    }
+
+    #[test]
+    fn select_disconnected() {
+        let (tx, rx) = channel::<i32>();
+        drop(tx);
+
+        let mut sel = Select::new();
+        let oper1 = sel.recv(&rx);
+        let oper = sel.select();
+        assert_eq!(oper.index(), oper1);
+        assert_eq!(oper.recv(&rx), Err(RecvError));
+
+        sel.remove(oper1);
+        assert!(sel.try_select().is_err());
+    }
+
+    #[test]
+    fn select_nothing_ready() {
+        let (_tx, rx) = channel::<i32>();
+        let (tx2, _rx2) = sync_channel::<i32>(0);
+
+        let mut sel = Select::new();
+        sel.recv(&rx);
+        sel.send(&tx2);
+        assert_eq!(sel.try_select().unwrap_err(), TrySelectError { _priv: () });
+
+        let start = Instant::now();
+        let timeout = Duration::from_millis(10);
+        assert!(sel.select_timeout(timeout).is_err());
+        assert!(start.elapsed() >= timeout);
+    }
+
+    #[test]
+    #[should_panic(expected = "passed a receiver that wasn't selected")]
+    fn select_wrong_receiver() {
+        let (tx1, rx1) = channel::<i32>();
+        let (_tx2, rx2) = channel::<i32>();
+        tx1.send(1).unwrap();
+
+        let mut sel = Select::new();
+        sel.recv(&rx1);
+        let _ = sel.select().recv(&rx2);
+    }
+}
@@ -8,6 +8,7 @@ pub mod os;
 #[path = "../../unix/path.rs"]
 pub mod path;
 pub mod pipe;
+#[path = "../../unsupported/process.rs"]
 pub mod process;
 
 // This enum is used as the storage for a bunch of types which can't actually exist.
@@ -2,8 +2,22 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+use crate::fmt;
 use crate::fs::{self, OpenOptions, Permissions};
 use crate::io;
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+use crate::os::unix::io::{AsRawFd, RawFd};
 use crate::path::Path;
 use crate::sys;
 use crate::sys::platform::fs::MetadataExt as UnixMetadataExt;
@@ -902,3 +916,158 @@ impl DirBuilderExt for fs::DirBuilder {
         self
     }
 }
+
+/// A handle to an open directory.
+///
+/// Paths passed to the `*_at` methods are resolved relative to this
+/// directory rather than to the current working directory, and the directory
+/// itself is never looked up by path again. This makes it possible to work on
+/// a directory tree without being vulnerable to another process renaming
+/// directories in it or replacing them with symlinks between two operations.
+///
+/// A `Dir` is closed when it is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(dir_fd)]
+/// use std::fs::OpenOptions;
+/// use std::os::unix::fs::Dir;
+///
+/// fn main() -> std::io::Result<()> {
+///     let dir = Dir::open("/var/log")?;
+///     let log = dir.open_at("app.log", OpenOptions::new().append(true).create(true))?;
+///     for entry in dir.read_dir()? {
+///         println!("{:?}", entry?.file_name());
+///     }
+///     dir.remove_at("app.log.old")?;
+///     # drop(log);
+///     Ok(())
+/// }
+/// ```
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+#[unstable(feature = "dir_fd", issue = "none")]
+pub struct Dir {
+    inner: sys::fs::Dir,
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+impl Dir {
+    /// Opens the directory at `path`.
+    ///
+    /// This corresponds to `open` with the `O_DIRECTORY` flag, so it fails if
+    /// `path` does not refer to a directory. Symlinks in `path` are followed.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Dir> {
+        sys::fs::Dir::open(path.as_ref()).map(|inner| Dir { inner })
+    }
+
+    /// Opens the subdirectory `path` of this directory.
+    ///
+    /// Unlike [`Dir::open`], this fails if the last component of `path` is a
+    /// symlink, so it can safely be used to descend into a directory tree.
+    ///
+    /// [`Dir::open`]: #method.open
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn open_dir_at<P: AsRef<Path>>(&self, path: P) -> io::Result<Dir> {
+        self.inner.open_dir_at(path.as_ref()).map(|inner| Dir { inner })
+    }
+
+    /// Opens the file `path` relative to this directory with the given
+    /// options, as if by `openat`.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn open_at<P: AsRef<Path>>(&self, path: P, opts: &OpenOptions) -> io::Result<fs::File> {
+        self.inner.open_at(path.as_ref(), opts.as_inner()).map(fs::File::from_inner)
+    }
+
+    /// Queries the metadata of `path` relative to this directory, following
+    /// symlinks, as if by `fstatat`.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn metadata_at<P: AsRef<Path>>(&self, path: P) -> io::Result<fs::Metadata> {
+        self.inner.metadata_at(path.as_ref(), true).map(fs::Metadata::from_inner)
+    }
+
+    /// Queries the metadata of `path` relative to this directory without
+    /// following a symlink in its last component, as if by `fstatat` with
+    /// `AT_SYMLINK_NOFOLLOW`.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn symlink_metadata_at<P: AsRef<Path>>(&self, path: P) -> io::Result<fs::Metadata> {
+        self.inner.metadata_at(path.as_ref(), false).map(fs::Metadata::from_inner)
+    }
+
+    /// Removes the file or symlink `path` relative to this directory, as if
+    /// by `unlinkat`.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn remove_at<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_at(path.as_ref())
+    }
+
+    /// Removes the empty directory `path` relative to this directory, as if
+    /// by `unlinkat` with `AT_REMOVEDIR`.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn remove_dir_at<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.inner.remove_dir_at(path.as_ref())
+    }
+
+    /// Renames `from`, relative to this directory, to `to`, relative to
+    /// `to_dir`, as if by `renameat`.
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn rename_at<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        from: P,
+        to_dir: &Dir,
+        to: Q,
+    ) -> io::Result<()> {
+        self.inner.rename_at(from.as_ref(), &to_dir.inner, to.as_ref())
+    }
+
+    /// Returns an iterator over the entries of this directory.
+    ///
+    /// Each call starts over from the first entry. The paths returned by
+    /// [`DirEntry::path`] are built from the path this directory was opened
+    /// with and are only informational: use [`DirEntry::file_name`] together
+    /// with the `*_at` methods to act on the entries.
+    ///
+    /// [`DirEntry::path`]: ../../../../std/fs/struct.DirEntry.html#method.path
+    /// [`DirEntry::file_name`]: ../../../../std/fs/struct.DirEntry.html#method.file_name
+    #[unstable(feature = "dir_fd", issue = "none")]
+    pub fn read_dir(&self) -> io::Result<fs::ReadDir> {
+        self.inner.read_dir().map(fs::ReadDir::from_inner)
+    }
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+#[unstable(feature = "dir_fd", issue = "none")]
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+#[unstable(feature = "dir_fd", issue = "none")]
+impl AsRawFd for Dir {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.fd().raw()
+    }
+}
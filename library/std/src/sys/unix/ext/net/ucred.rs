@@ -0,0 +1,47 @@
+//! Credentials of the process on the other end of a Unix socket.
+
+use crate::io;
+use crate::sys::net::Socket;
+
+/// Credentials for a UNIX process for credentials passing.
+#[unstable(feature = "peer_credentials_unix_socket", issue = "none")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UCred {
+    /// The UID part of the peer credential. This is the effective UID of the process at the domain
+    /// socket's endpoint.
+    pub uid: libc::uid_t,
+    /// The GID part of the peer credential. This is the effective GID of the process at the domain
+    /// socket's endpoint.
+    pub gid: libc::gid_t,
+    /// The PID part of the peer credential. This field is optional because the PID part of the
+    /// peer credentials is not supported on every platform. On platforms where the mechanism to
+    /// discover the PID exists, this field will be populated with the PID of the peer process.
+    pub pid: Option<libc::pid_t>,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(super) fn peer_cred(socket: &Socket) -> io::Result<UCred> {
+    use crate::sys_common::net::getsockopt;
+
+    // The credentials are those of the peer at the time it called `connect`
+    // or `socketpair`, not when it last wrote to the socket.
+    let ucred: libc::ucred = getsockopt(socket, libc::SOL_SOCKET, libc::SO_PEERCRED)?;
+    Ok(UCred { uid: ucred.uid, gid: ucred.gid, pid: Some(ucred.pid) })
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+pub(super) fn peer_cred(socket: &Socket) -> io::Result<UCred> {
+    use crate::sys::cvt;
+    use crate::sys_common::AsInner;
+
+    let mut cred = UCred { uid: 1, gid: 1, pid: None };
+    cvt(unsafe { libc::getpeereid(*socket.as_inner(), &mut cred.uid, &mut cred.gid) })?;
+    Ok(cred)
+}
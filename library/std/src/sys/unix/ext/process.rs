@@ -150,6 +150,127 @@ impl CommandExt for process::Command {
     }
 }
 
+mod private {
+    /// This trait being unreachable from outside the crate prevents outside
+    /// implementations of the extension traits below, so that methods can be
+    /// added to them later without breaking anyone.
+    #[unstable(feature = "process_set_process_group", issue = "none")]
+    pub trait Sealed {}
+
+    impl Sealed for crate::process::Child {}
+    impl Sealed for crate::process::Command {}
+}
+
+/// Unix-specific extensions to the [`process::Command`] builder for putting
+/// the child into a new process group or session.
+///
+/// This trait is sealed: it cannot be implemented outside the standard
+/// library.
+///
+/// [`process::Command`]: ../../../../std/process/struct.Command.html
+#[unstable(feature = "process_set_process_group", issue = "none")]
+pub trait CommandGroupExt: private::Sealed {
+    /// Sets the process group ID of the child process. This translates to a
+    /// `setpgid` call in the child process, so the child will be moved into
+    /// process group `pgroup`.
+    ///
+    /// Passing 0 puts the child into a new process group whose ID is the
+    /// child's pid, which is the usual way to stop a terminal's job control
+    /// signals (like the SIGINT from Ctrl-C) from reaching it.
+    ///
+    /// Failure in the `setpgid` call will cause the spawn to fail.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_set_process_group)]
+    /// use std::os::unix::process::CommandGroupExt;
+    /// use std::process::Command;
+    ///
+    /// Command::new("sleep")
+    ///     .arg("10")
+    ///     .process_group(0)
+    ///     .spawn()
+    ///     .expect("sleep failed to start");
+    /// ```
+    #[unstable(feature = "process_set_process_group", issue = "none")]
+    fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Sets whether the child process should be started in a new session,
+    /// by calling `setsid` in the child. The child then becomes the leader
+    /// of a new session and of a new process group, with no controlling
+    /// terminal.
+    ///
+    /// `setsid` fails if the child is already a process group leader, so
+    /// this can't be combined with [`process_group`]; doing so will cause
+    /// the spawn to fail with `EPERM`.
+    ///
+    /// [`process_group`]: #tymethod.process_group
+    #[unstable(feature = "process_setsid", issue = "none")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
+}
+
+#[unstable(feature = "process_set_process_group", issue = "none")]
+impl CommandGroupExt for process::Command {
+    fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
+        self.as_inner_mut().pgroup(pgroup);
+        self
+    }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().setsid(setsid);
+        self
+    }
+}
+
+/// Unix-specific extensions to [`process::Child`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard
+/// library.
+///
+/// [`process::Child`]: ../../../../std/process/struct.Child.html
+#[unstable(feature = "unix_send_signal", issue = "none")]
+pub trait ChildExt: private::Sealed {
+    /// Sends the signal `signal` to the child process.
+    ///
+    /// Unlike [`Child::kill`], which always sends `SIGKILL`, any signal can
+    /// be sent, e.g. `SIGTERM` to ask the child to shut down gracefully.
+    /// On Linux, if a pidfd was created for the child, the signal is sent
+    /// through it with `pidfd_send_signal`, otherwise `kill` is used.
+    ///
+    /// # Errors
+    ///
+    /// Like [`Child::kill`], this returns an error of kind `InvalidInput` if
+    /// the child has already been waited on, as its pid may have been reused
+    /// by an unrelated process by then. Errors from the underlying system
+    /// call are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_send_signal)]
+    /// use std::os::unix::process::{ChildExt, ExitStatusExt};
+    /// use std::process::Command;
+    ///
+    /// const SIGTERM: i32 = 15;
+    ///
+    /// let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    /// child.signal(SIGTERM).unwrap();
+    /// assert_eq!(child.wait().unwrap().signal(), Some(SIGTERM));
+    /// ```
+    ///
+    /// [`Child::kill`]: ../../../../std/process/struct.Child.html#method.kill
+    #[unstable(feature = "unix_send_signal", issue = "none")]
+    fn signal(&self, signal: i32) -> io::Result<()>;
+}
+
+#[unstable(feature = "unix_send_signal", issue = "none")]
+impl ChildExt for process::Child {
+    fn signal(&self, signal: i32) -> io::Result<()> {
+        self.as_inner().send_signal(signal)
+    }
+}
+
 /// Unix-specific extensions to [`process::ExitStatus`].
 ///
 /// [`process::ExitStatus`]: ../../../../std/process/struct.ExitStatus.html
@@ -161,8 +282,34 @@ pub trait ExitStatusExt {
     fn from_raw(raw: i32) -> Self;
 
     /// If the process was terminated by a signal, returns that signal.
+    ///
+    /// In other words, if `WIFSIGNALED`, this returns `WTERMSIG`.
     #[stable(feature = "rust1", since = "1.0.0")]
     fn signal(&self) -> Option<i32>;
+
+    /// If the process was terminated by a signal, says whether it dumped core.
+    ///
+    /// The default implementation returns `false`; it is only provided so
+    /// that adding this method doesn't break implementations of this trait
+    /// outside the standard library.
+    #[unstable(feature = "unix_process_wait_more", issue = "none")]
+    fn core_dumped(&self) -> bool {
+        false
+    }
+
+    /// If the process was stopped by a signal, returns that signal.
+    ///
+    /// In other words, if `WIFSTOPPED`, this returns `WSTOPSIG`. This is only
+    /// possible if the status came from a `wait` system call which was passed
+    /// `WUNTRACED`, and was then converted into an `ExitStatus`.
+    ///
+    /// The default implementation returns `None`, see [`core_dumped`].
+    ///
+    /// [`core_dumped`]: #method.core_dumped
+    #[unstable(feature = "unix_process_wait_more", issue = "none")]
+    fn stopped_signal(&self) -> Option<i32> {
+        None
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -174,6 +321,14 @@ impl ExitStatusExt for process::ExitStatus {
     fn signal(&self) -> Option<i32> {
         self.as_inner().signal()
     }
+
+    fn core_dumped(&self) -> bool {
+        self.as_inner().core_dumped()
+    }
+
+    fn stopped_signal(&self) -> Option<i32> {
+        self.as_inner().stopped_signal()
+    }
 }
 
 #[stable(feature = "process_extensions", since = "1.2.0")]
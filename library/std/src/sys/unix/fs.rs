@@ -2,7 +2,7 @@ use crate::os::unix::prelude::*;
 
 use crate::ffi::{CStr, CString, OsStr, OsString};
 use crate::fmt;
-use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, ReadBuf, SeekFrom};
 use crate::mem;
 use crate::path::{Path, PathBuf};
 use crate::ptr;
@@ -48,6 +48,12 @@ use libc::{
     dirent64, fstat64, ftruncate64, lseek64, lstat64, off64_t, open64, readdir64_r, stat64,
 };
 
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+))]
 pub use crate::sys_common::fs::remove_dir_all;
 
 pub struct File(FileDesc);
@@ -179,7 +185,7 @@ cfg_has_statx! {{
 
 // all DirEntry's will have a reference to this struct
 struct InnerReadDir {
-    dirp: Dir,
+    dirp: DirStream,
     root: PathBuf,
 }
 
@@ -189,10 +195,10 @@ pub struct ReadDir {
     end_of_stream: bool,
 }
 
-struct Dir(*mut libc::DIR);
+struct DirStream(*mut libc::DIR);
 
-unsafe impl Send for Dir {}
-unsafe impl Sync for Dir {}
+unsafe impl Send for DirStream {}
+unsafe impl Sync for DirStream {}
 
 pub struct DirEntry {
     entry: dirent64,
@@ -488,7 +494,7 @@ impl Iterator for ReadDir {
     }
 }
 
-impl Drop for Dir {
+impl Drop for DirStream {
     fn drop(&mut self) {
         let r = unsafe { libc::closedir(self.0) };
         debug_assert_eq!(r, 0);
@@ -779,6 +785,10 @@ impl File {
         self.0.read(buf)
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.0.read_buf(buf)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -843,6 +853,91 @@ impl File {
     }
 }
 
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+impl File {
+    pub fn lock_shared(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.0.raw(), libc::LOCK_SH) })?;
+        Ok(())
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.0.raw(), libc::LOCK_EX) })?;
+        Ok(())
+    }
+
+    // `EWOULDBLOCK` already maps to `ErrorKind::WouldBlock`, so a contended
+    // lock needs no special handling here.
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.0.raw(), libc::LOCK_SH | libc::LOCK_NB) })?;
+        Ok(())
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.0.raw(), libc::LOCK_EX | libc::LOCK_NB) })?;
+        Ok(())
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.0.raw(), libc::LOCK_UN) })?;
+        Ok(())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+impl File {
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn lock_unsupported() -> io::Error {
+    io::Error::new(ErrorKind::Other, "file locking is not supported on this platform")
+}
+
 impl DirBuilder {
     pub fn new() -> DirBuilder {
         DirBuilder { mode: 0o777 }
@@ -943,7 +1038,7 @@ pub fn readdir(p: &Path) -> io::Result<ReadDir> {
         if ptr.is_null() {
             Err(Error::last_os_error())
         } else {
-            let inner = InnerReadDir { dirp: Dir(ptr), root };
+            let inner = InnerReadDir { dirp: DirStream(ptr), root };
             Ok(ReadDir { inner: Arc::new(inner), end_of_stream: false })
         }
     }
@@ -1067,6 +1162,264 @@ pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
     Ok(PathBuf::from(OsString::from_vec(buf)))
 }
 
+/// A handle to an open directory, used to operate on its entries without
+/// resolving the directory's own path again.
+///
+/// Entry names passed to the `*_at` methods are resolved relative to the
+/// directory, so renaming or replacing the directory (or any of its ancestors)
+/// with a symlink after it was opened has no effect on them.
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+pub struct Dir {
+    fd: FileDesc,
+    // Only used for `Debug` and for the paths of entries returned by
+    // `read_dir`.
+    root: PathBuf,
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+impl Dir {
+    pub fn open(path: &Path) -> io::Result<Dir> {
+        let p = cstr(path)?;
+        let flags = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_CLOEXEC;
+        let fd = cvt_r(|| unsafe { open64(p.as_ptr(), flags) })?;
+        Ok(Dir { fd: FileDesc::new(fd), root: path.to_path_buf() })
+    }
+
+    /// Opens the subdirectory `path`. If the last component of `path` is a
+    /// symlink this fails instead of following it.
+    pub fn open_dir_at(&self, path: &Path) -> io::Result<Dir> {
+        let p = cstr(path)?;
+        let flags = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_NOFOLLOW | libc::O_CLOEXEC;
+        let fd = cvt_r(|| unsafe { libc::openat(self.fd.raw(), p.as_ptr(), flags) })?;
+        Ok(Dir { fd: FileDesc::new(fd), root: self.root.join(path) })
+    }
+
+    pub fn open_at(&self, path: &Path, opts: &OpenOptions) -> io::Result<File> {
+        let p = cstr(path)?;
+        let flags = libc::O_CLOEXEC
+            | opts.get_access_mode()?
+            | opts.get_creation_mode()?
+            | (opts.custom_flags as c_int & !libc::O_ACCMODE);
+        // See `File::open_c` for why the mode is passed as a `c_int`.
+        let fd = cvt_r(|| unsafe {
+            libc::openat(self.fd.raw(), p.as_ptr(), flags, opts.mode as c_int)
+        })?;
+        Ok(File(FileDesc::new(fd)))
+    }
+
+    pub fn metadata_at(&self, path: &Path, follow_symlinks: bool) -> io::Result<FileAttr> {
+        #[cfg(not(any(target_os = "linux", target_os = "emscripten", target_os = "android")))]
+        use libc::fstatat as fstatat64;
+
+        let p = cstr(path)?;
+        let flags = if follow_symlinks { 0 } else { libc::AT_SYMLINK_NOFOLLOW };
+
+        cfg_has_statx! {
+            if let Some(ret) = unsafe { try_statx(
+                self.fd.raw(),
+                p.as_ptr(),
+                flags | libc::AT_STATX_SYNC_AS_STAT,
+                libc::STATX_ALL,
+            ) } {
+                return ret;
+            }
+        }
+
+        let mut stat: stat64 = unsafe { mem::zeroed() };
+        cvt(unsafe { fstatat64(self.fd.raw(), p.as_ptr(), &mut stat, flags) })?;
+        Ok(FileAttr::from_stat64(stat))
+    }
+
+    pub fn remove_at(&self, path: &Path) -> io::Result<()> {
+        let p = cstr(path)?;
+        cvt(unsafe { libc::unlinkat(self.fd.raw(), p.as_ptr(), 0) })?;
+        Ok(())
+    }
+
+    pub fn remove_dir_at(&self, path: &Path) -> io::Result<()> {
+        let p = cstr(path)?;
+        cvt(unsafe { libc::unlinkat(self.fd.raw(), p.as_ptr(), libc::AT_REMOVEDIR) })?;
+        Ok(())
+    }
+
+    pub fn rename_at(&self, old: &Path, new_dir: &Dir, new: &Path) -> io::Result<()> {
+        let old = cstr(old)?;
+        let new = cstr(new)?;
+        cvt(unsafe {
+            libc::renameat(self.fd.raw(), old.as_ptr(), new_dir.fd.raw(), new.as_ptr())
+        })?;
+        Ok(())
+    }
+
+    pub fn read_dir(&self) -> io::Result<ReadDir> {
+        // `fdopendir` takes ownership of the descriptor it is given, so hand
+        // it a duplicate and keep ours usable.
+        let fd = self.fd.duplicate()?;
+        unsafe {
+            let ptr = libc::fdopendir(fd.raw());
+            if ptr.is_null() {
+                return Err(Error::last_os_error());
+            }
+            fd.into_raw();
+            // The duplicate shares its offset with `self.fd`, which an earlier
+            // `read_dir` may have advanced.
+            libc::rewinddir(ptr);
+            let inner = InnerReadDir { dirp: DirStream(ptr), root: self.root.clone() };
+            Ok(ReadDir { inner: Arc::new(inner), end_of_stream: false })
+        }
+    }
+
+    pub fn fd(&self) -> &FileDesc {
+        &self.fd
+    }
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+impl fmt::Debug for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dir").field("fd", &self.fd.raw()).field("path", &self.root).finish()
+    }
+}
+
+/// Removes a directory tree without following symlinks.
+///
+/// Every entry is removed relative to an open handle of its parent directory,
+/// so replacing a directory inside the tree with a symlink while this runs
+/// cannot redirect the removal to a location outside of the tree.
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+pub fn remove_dir_all(p: &Path) -> io::Result<()> {
+    // A symlink passed in directly is removed itself rather than its target.
+    // Should it be swapped for a symlink after this check, opening it below
+    // fails because `Dir::open_dir_at` refuses to follow symlinks.
+    if lstat(p)?.file_type().is_symlink() {
+        return unlink(p);
+    }
+    let (parent, name) = match (p.parent(), p.file_name()) {
+        (Some(parent), Some(name)) => {
+            let parent = if parent.as_os_str().is_empty() { Path::new(".") } else { parent };
+            (parent, Path::new(name))
+        }
+        // `/`, `..` and friends can't be named relative to a parent; empty
+        // them and let `rmdir` report the error as it always has.
+        _ => {
+            remove_dir_contents(Dir::open(p)?)?;
+            return rmdir(p);
+        }
+    };
+    let parent = Dir::open(parent)?;
+    remove_dir_contents(parent.open_dir_at(name)?)?;
+    parent.remove_dir_at(name)
+}
+
+/// Empties `dir` without recursing, so that deep trees neither overflow the
+/// stack nor run out of file descriptors: only the directory currently being
+/// emptied is kept open, and its parent is reopened through `..` once it is
+/// empty. The device and inode of every directory on the way down are
+/// recorded, so that a directory moved elsewhere in the meantime is detected
+/// instead of removing entries from the wrong parent.
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+fn remove_dir_contents(mut dir: Dir) -> io::Result<()> {
+    fn id(dir: &Dir) -> io::Result<(u64, u64)> {
+        let attr = dir.metadata_at(Path::new("."), false)?;
+        Ok((attr.stat.st_dev as u64, attr.stat.st_ino as u64))
+    }
+
+    // The names of the directories entered so far, along with the identity
+    // of the directory containing each of them.
+    let mut stack: Vec<(PathBuf, (u64, u64))> = Vec::new();
+    loop {
+        match next_child_dir(&dir)? {
+            Some(name) => match dir.open_dir_at(&name) {
+                Ok(child) => {
+                    stack.push((name, id(&dir)?));
+                    dir = child;
+                }
+                // The directory was replaced by something else since it was
+                // listed. Opening a symlink with `O_NOFOLLOW` fails with
+                // `ELOOP` on most platforms, but with `EMLINK` on FreeBSD.
+                Err(ref e)
+                    if e.raw_os_error() == Some(libc::ELOOP)
+                        || e.raw_os_error() == Some(libc::EMLINK)
+                        || e.raw_os_error() == Some(libc::ENOTDIR) =>
+                {
+                    dir.remove_at(&name)?;
+                }
+                Err(e) => return Err(e),
+            },
+            None => {
+                let (name, parent_id) = match stack.pop() {
+                    Some(entry) => entry,
+                    None => return Ok(()),
+                };
+                let mut parent = dir.open_dir_at(Path::new(".."))?;
+                if let Some(root) = dir.root.parent() {
+                    parent.root = root.to_path_buf();
+                }
+                if id(&parent)? != parent_id {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        "directory was moved while its contents were being removed",
+                    ));
+                }
+                dir = parent;
+                dir.remove_dir_at(&name)?;
+            }
+        }
+    }
+}
+
+/// Removes all entries of `dir` which are not directories, and returns the
+/// name of the first directory found, if any.
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "redox",
+    target_os = "l4re"
+)))]
+fn next_child_dir(dir: &Dir) -> io::Result<Option<PathBuf>> {
+    for child in dir.read_dir()? {
+        let child = child?;
+        let name = Path::new(OsStr::from_bytes(child.name_bytes()));
+        // The entry type is only a hint: if a directory was replaced by a
+        // symlink in the meantime, `open_dir_at` fails and the symlink itself
+        // is unlinked.
+        let is_dir = match child.file_type() {
+            Ok(ty) => ty.is_dir(),
+            Err(_) => dir.metadata_at(name, false)?.file_type().is_dir(),
+        };
+        if is_dir {
+            return Ok(Some(name.to_path_buf()));
+        }
+        dir.remove_at(name)?;
+    }
+    Ok(None)
+}
+
 fn open_from(from: &Path) -> io::Result<(crate::fs::File, crate::fs::Metadata)> {
     use crate::fs::File;
 
@@ -1121,81 +1474,20 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
-    use crate::cmp;
-    use crate::sync::atomic::{AtomicBool, Ordering};
-
-    // Kernel prior to 4.5 don't have copy_file_range
-    // We store the availability in a global to avoid unnecessary syscalls
-    static HAS_COPY_FILE_RANGE: AtomicBool = AtomicBool::new(true);
-
-    unsafe fn copy_file_range(
-        fd_in: libc::c_int,
-        off_in: *mut libc::loff_t,
-        fd_out: libc::c_int,
-        off_out: *mut libc::loff_t,
-        len: libc::size_t,
-        flags: libc::c_uint,
-    ) -> libc::c_long {
-        libc::syscall(libc::SYS_copy_file_range, fd_in, off_in, fd_out, off_out, len, flags)
-    }
+    use super::kernel_copy::{copy_regular_files, CopyResult};
 
     let (mut reader, reader_metadata) = open_from(from)?;
-    let len = reader_metadata.len();
+    let max_len = u64::MAX;
     let (mut writer, _) = open_to_and_set_permissions(to, reader_metadata)?;
 
-    let has_copy_file_range = HAS_COPY_FILE_RANGE.load(Ordering::Relaxed);
-    let mut written = 0u64;
-    while written < len {
-        let copy_result = if has_copy_file_range {
-            let bytes_to_copy = cmp::min(len - written, usize::MAX as u64) as usize;
-            let copy_result = unsafe {
-                // We actually don't have to adjust the offsets,
-                // because copy_file_range adjusts the file offset automatically
-                cvt(copy_file_range(
-                    reader.as_raw_fd(),
-                    ptr::null_mut(),
-                    writer.as_raw_fd(),
-                    ptr::null_mut(),
-                    bytes_to_copy,
-                    0,
-                ))
-            };
-            if let Err(ref copy_err) = copy_result {
-                match copy_err.raw_os_error() {
-                    Some(libc::ENOSYS) | Some(libc::EPERM) => {
-                        HAS_COPY_FILE_RANGE.store(false, Ordering::Relaxed);
-                    }
-                    _ => {}
-                }
-            }
-            copy_result
-        } else {
-            Err(io::Error::from_raw_os_error(libc::ENOSYS))
-        };
-        match copy_result {
-            Ok(ret) => written += ret as u64,
-            Err(err) => {
-                match err.raw_os_error() {
-                    Some(os_err)
-                        if os_err == libc::ENOSYS
-                            || os_err == libc::EXDEV
-                            || os_err == libc::EINVAL
-                            || os_err == libc::EPERM =>
-                    {
-                        // Try fallback io::copy if either:
-                        // - Kernel version is < 4.5 (ENOSYS)
-                        // - Files are mounted on different fs (EXDEV)
-                        // - copy_file_range is disallowed, for example by seccomp (EPERM)
-                        // - copy_file_range cannot be used with pipes or device nodes (EINVAL)
-                        assert_eq!(written, 0);
-                        return io::copy(&mut reader, &mut writer);
-                    }
-                    _ => return Err(err),
-                }
-            }
-        }
+    match copy_regular_files(reader.as_raw_fd(), writer.as_raw_fd(), max_len) {
+        CopyResult::Ended(bytes) => Ok(bytes),
+        CopyResult::Error(e, _) => Err(e),
+        CopyResult::Fallback(written) => match io::generic_copy(&mut reader, &mut writer) {
+            Ok(bytes) => Ok(bytes + written),
+            Err(e) => Err(e),
+        },
     }
-    Ok(written)
 }
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
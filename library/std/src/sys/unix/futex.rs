@@ -0,0 +1,60 @@
+//! Thin wrappers around the Linux `futex` system call, used to build the
+//! locks in this module without allocating any OS resources.
+
+use crate::convert::TryInto;
+use crate::ptr;
+use crate::sync::atomic::AtomicU32;
+use crate::time::Duration;
+
+/// Waits for a `futex_wake` operation to wake us.
+///
+/// Returns directly if the futex doesn't hold the expected value.
+///
+/// Returns false on timeout, and true in all other cases, including spurious
+/// wakeups and interruption by a signal.
+pub fn futex_wait(futex: &AtomicU32, expected: u32, timeout: Option<Duration>) -> bool {
+    // A timeout too large to represent is treated as no timeout at all.
+    let timespec = timeout.and_then(|d| {
+        Some(libc::timespec {
+            tv_sec: d.as_secs().try_into().ok()?,
+            tv_nsec: d.subsec_nanos() as _,
+        })
+    });
+    let r = unsafe {
+        libc::syscall(
+            libc::SYS_futex,
+            futex as *const AtomicU32,
+            libc::FUTEX_WAIT | libc::FUTEX_PRIVATE_FLAG,
+            expected,
+            timespec.as_ref().map_or(ptr::null(), |t| t as *const libc::timespec),
+        )
+    };
+    !(r < 0 && super::os::errno() == libc::ETIMEDOUT)
+}
+
+/// Wakes up one thread that's blocked on `futex_wait` on this futex.
+///
+/// Returns true if this actually woke up such a thread,
+/// or false if no thread was waiting on this futex.
+pub fn futex_wake(futex: &AtomicU32) -> bool {
+    unsafe {
+        libc::syscall(
+            libc::SYS_futex,
+            futex as *const AtomicU32,
+            libc::FUTEX_WAKE | libc::FUTEX_PRIVATE_FLAG,
+            1,
+        ) > 0
+    }
+}
+
+/// Wakes up all threads that are waiting on `futex_wait` on this futex.
+pub fn futex_wake_all(futex: &AtomicU32) {
+    unsafe {
+        libc::syscall(
+            libc::SYS_futex,
+            futex as *const AtomicU32,
+            libc::FUTEX_WAKE | libc::FUTEX_PRIVATE_FLAG,
+            i32::MAX,
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use super::futex::{futex_wait, futex_wake};
+use crate::cell::UnsafeCell;
+use crate::sync::atomic::{
+    spin_loop_hint, AtomicU32, AtomicUsize,
+    Ordering::{Acquire, Relaxed, Release},
+};
+
+pub struct Mutex {
+    /// 0: unlocked
+    /// 1: locked, no other threads waiting
+    /// 2: locked, and other threads waiting (contended)
+    futex: AtomicU32,
+}
+
+// A futex is a plain integer that may be moved freely while it isn't in use,
+// so there is no need to box it.
+pub type MovableMutex = Mutex;
+
+impl Mutex {
+    pub const fn new() -> Mutex {
+        Mutex { futex: AtomicU32::new(0) }
+    }
+
+    #[inline]
+    pub unsafe fn init(&mut self) {}
+
+    #[inline]
+    pub unsafe fn try_lock(&self) -> bool {
+        self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_ok()
+    }
+
+    #[inline]
+    pub unsafe fn lock(&self) {
+        if self.futex.compare_exchange(0, 1, Acquire, Relaxed).is_err() {
+            self.lock_contended();
+        }
+    }
+
+    #[cold]
+    fn lock_contended(&self) {
+        // Spin first to speed things up if the lock is released quickly.
+        let mut state = self.spin();
+
+        // If it's unlocked now, attempt to take the lock
+        // without marking it as contended.
+        if state == 0 {
+            match self.futex.compare_exchange(0, 1, Acquire, Relaxed) {
+                Ok(_) => return, // Locked!
+                Err(s) => state = s,
+            }
+        }
+
+        loop {
+            // Put the lock in contended state.
+            // We avoid an unnecessary write if it is already set to 2,
+            // to be friendlier for the caches.
+            if state != 2 && self.futex.swap(2, Acquire) == 0 {
+                // We changed it from 0 to 2, so we just successfully locked it.
+                return;
+            }
+
+            // Wait for the futex to change state, assuming it is still 2.
+            futex_wait(&self.futex, 2, None);
+
+            // Spin again after waking up.
+            state = self.spin();
+        }
+    }
+
+    fn spin(&self) -> u32 {
+        let mut spin = 100;
+        loop {
+            // We only use `load` (and not `swap` or `compare_exchange`)
+            // while spinning, to be easier on the caches.
+            let state = self.futex.load(Relaxed);
+
+            // We stop spinning when the mutex is unlocked (0),
+            // but also when it's contended (2).
+            if state != 1 || spin == 0 {
+                return state;
+            }
+
+            spin_loop_hint();
+            spin -= 1;
+        }
+    }
+
+    #[inline]
+    pub unsafe fn unlock(&self) {
+        if self.futex.swap(0, Release) == 2 {
+            // We only wake up one thread. When that thread locks the mutex, it
+            // will mark the mutex as contended (2) (see lock_contended above),
+            // which makes sure that any other waiting threads will also be
+            // woken up eventually.
+            self.wake();
+        }
+    }
+
+    #[cold]
+    fn wake(&self) {
+        futex_wake(&self.futex);
+    }
+
+    #[inline]
+    pub unsafe fn destroy(&self) {}
+}
+
+/// A reentrant mutex built on top of the futex-based `Mutex`, tracking the
+/// owning thread and the number of times it has been locked by that thread.
+pub struct ReentrantMutex {
+    mutex: Mutex,
+    owner: AtomicUsize,
+    lock_count: UnsafeCell<u32>,
+}
+
+unsafe impl Send for ReentrantMutex {}
+unsafe impl Sync for ReentrantMutex {}
+
+// `owner` is only ever set to the current thread's id while that thread holds
+// `mutex`, and `lock_count` is only accessed by the thread that holds `mutex`.
+// Comparing `owner` against our own id therefore tells us, without any further
+// synchronization, whether we already hold the lock.
+impl ReentrantMutex {
+    pub const unsafe fn uninitialized() -> ReentrantMutex {
+        ReentrantMutex {
+            mutex: Mutex::new(),
+            owner: AtomicUsize::new(0),
+            lock_count: UnsafeCell::new(0),
+        }
+    }
+
+    pub unsafe fn init(&self) {}
+
+    pub unsafe fn lock(&self) {
+        let this_thread = current_thread_unique_ptr();
+        if self.owner.load(Relaxed) == this_thread {
+            self.increment_lock_count();
+        } else {
+            self.mutex.lock();
+            self.owner.store(this_thread, Relaxed);
+            debug_assert_eq!(*self.lock_count.get(), 0);
+            *self.lock_count.get() = 1;
+        }
+    }
+
+    #[inline]
+    pub unsafe fn try_lock(&self) -> bool {
+        let this_thread = current_thread_unique_ptr();
+        if self.owner.load(Relaxed) == this_thread {
+            self.increment_lock_count();
+            true
+        } else if self.mutex.try_lock() {
+            self.owner.store(this_thread, Relaxed);
+            debug_assert_eq!(*self.lock_count.get(), 0);
+            *self.lock_count.get() = 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    unsafe fn increment_lock_count(&self) {
+        *self.lock_count.get() = (*self.lock_count.get())
+            .checked_add(1)
+            .expect("lock count overflow in reentrant mutex");
+    }
+
+    pub unsafe fn unlock(&self) {
+        *self.lock_count.get() -= 1;
+        if *self.lock_count.get() == 0 {
+            self.owner.store(0, Relaxed);
+            self.mutex.unlock();
+        }
+    }
+
+    pub unsafe fn destroy(&self) {}
+}
+
+/// Gets an address that is unique per running thread.
+///
+/// This can be used as a non-null usize-sized ID.
+fn current_thread_unique_ptr() -> usize {
+    // Use a non-drop type to make sure it's still available during thread destruction.
+    thread_local! { static X: u8 = 0 }
+    X.with(|x| x as *const u8 as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mutex;
+    use crate::cell::UnsafeCell;
+    use crate::sync::atomic::{AtomicUsize, Ordering};
+    use crate::sync::Arc;
+    use crate::thread;
+
+    /// The pthread mutex used on other Unix platforms, as a baseline.
+    struct PthreadMutex(UnsafeCell<libc::pthread_mutex_t>);
+
+    unsafe impl Send for PthreadMutex {}
+    unsafe impl Sync for PthreadMutex {}
+
+    trait RawLock: Send + Sync + 'static {
+        fn lock_unlock(&self);
+    }
+
+    impl RawLock for Mutex {
+        fn lock_unlock(&self) {
+            unsafe {
+                self.lock();
+                self.unlock();
+            }
+        }
+    }
+
+    impl RawLock for PthreadMutex {
+        fn lock_unlock(&self) {
+            unsafe {
+                libc::pthread_mutex_lock(self.0.get());
+                libc::pthread_mutex_unlock(self.0.get());
+            }
+        }
+    }
+
+    fn pthread_mutex() -> PthreadMutex {
+        PthreadMutex(UnsafeCell::new(libc::PTHREAD_MUTEX_INITIALIZER))
+    }
+
+    fn uncontended<L: RawLock>(b: &mut test::Bencher, lock: L) {
+        b.iter(|| lock.lock_unlock());
+    }
+
+    // Four threads each take and release the lock a thousand times.
+    fn contended<L: RawLock>(b: &mut test::Bencher, lock: L) {
+        let lock = Arc::new(lock);
+        b.iter(|| {
+            let threads: Vec<_> = (0..4)
+                .map(|_| {
+                    let lock = lock.clone();
+                    thread::spawn(move || {
+                        for _ in 0..1000 {
+                            lock.lock_unlock();
+                        }
+                    })
+                })
+                .collect();
+            for t in threads {
+                t.join().unwrap();
+            }
+        });
+    }
+
+    #[test]
+    fn smoke() {
+        let m = Arc::new(Mutex::new());
+        let count = Arc::new(AtomicUsize::new(0));
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let (m, count) = (m.clone(), count.clone());
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        unsafe {
+                            m.lock();
+                            assert!(!m.try_lock());
+                            // Not atomic as a whole; only correct under the lock.
+                            let c = count.load(Ordering::Relaxed);
+                            count.store(c + 1, Ordering::Relaxed);
+                            m.unlock();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for t in threads {
+            t.join().unwrap();
+        }
+        assert_eq!(count.load(Ordering::Relaxed), 4000);
+    }
+
+    #[bench]
+    fn bench_uncontended_futex(b: &mut test::Bencher) {
+        uncontended(b, Mutex::new());
+    }
+
+    #[bench]
+    fn bench_uncontended_pthread(b: &mut test::Bencher) {
+        uncontended(b, pthread_mutex());
+    }
+
+    #[bench]
+    fn bench_contended_futex(b: &mut test::Bencher) {
+        contended(b, Mutex::new());
+    }
+
+    #[bench]
+    fn bench_contended_pthread(b: &mut test::Bencher) {
+        contended(b, pthread_mutex());
+    }
+}
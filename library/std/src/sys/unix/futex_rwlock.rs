@@ -0,0 +1,372 @@
+use super::futex::{futex_wait, futex_wake_all};
+use crate::sync::atomic::{
+    spin_loop_hint, AtomicU32,
+    Ordering::{Acquire, Relaxed, Release},
+};
+
+pub struct RWLock {
+    // The state consists of a 30-bit reader counter, a 'readers waiting' flag,
+    // and a 'writers waiting' flag.
+    // Bits 0..30:
+    //   0: Unlocked
+    //   1..=0x3FFF_FFFE: Locked by N readers
+    //   0x3FFF_FFFF: Write locked
+    // Bit 30: Readers are waiting on this futex.
+    // Bit 31: Writers are waiting on this futex.
+    //
+    // Waiting readers and writers both sleep on this one futex. Whichever
+    // thread brings the lock back to the unlocked state clears both flags and
+    // wakes everyone up to compete for the lock again. While writers are
+    // waiting, new readers are not let in, so writers can't be starved.
+    state: AtomicU32,
+}
+
+pub type MovableRWLock = RWLock;
+
+const READ_LOCKED: u32 = 1;
+const MASK: u32 = (1 << 30) - 1;
+const WRITE_LOCKED: u32 = MASK;
+const MAX_READERS: u32 = MASK - 1;
+const READERS_WAITING: u32 = 1 << 30;
+const WRITERS_WAITING: u32 = 1 << 31;
+
+fn is_unlocked(state: u32) -> bool {
+    state & MASK == 0
+}
+
+fn is_write_locked(state: u32) -> bool {
+    state & MASK == WRITE_LOCKED
+}
+
+fn has_readers_waiting(state: u32) -> bool {
+    state & READERS_WAITING != 0
+}
+
+fn has_writers_waiting(state: u32) -> bool {
+    state & WRITERS_WAITING != 0
+}
+
+fn is_read_lockable(state: u32) -> bool {
+    // This also returns false if the counter could overflow if we tried to read lock it.
+    //
+    // We don't allow read-locking while any thread is waiting, even if the lock is
+    // unlocked. The only situation when this happens is right after unlocking, at which
+    // point the unlocking thread is about to wake up the waiting threads, which have
+    // priority over threads that just arrived.
+    state & MASK < MAX_READERS && !has_readers_waiting(state) && !has_writers_waiting(state)
+}
+
+fn has_reached_max_readers(state: u32) -> bool {
+    state & MASK == MAX_READERS
+}
+
+impl RWLock {
+    pub const fn new() -> RWLock {
+        RWLock { state: AtomicU32::new(0) }
+    }
+
+    #[inline]
+    pub unsafe fn try_read(&self) -> bool {
+        let mut state = self.state.load(Relaxed);
+        while is_read_lockable(state) {
+            match self.state.compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed) {
+                Ok(_) => return true,
+                Err(s) => state = s,
+            }
+        }
+        false
+    }
+
+    #[inline]
+    pub unsafe fn read(&self) {
+        let state = self.state.load(Relaxed);
+        if !is_read_lockable(state)
+            || self
+                .state
+                .compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
+                .is_err()
+        {
+            self.read_contended();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn read_unlock(&self) {
+        let state = self.state.fetch_sub(READ_LOCKED, Release) - READ_LOCKED;
+
+        // It's impossible for a reader to be waiting on a read-locked RWLock,
+        // except if there is also a writer waiting.
+        debug_assert!(!has_readers_waiting(state) || has_writers_waiting(state));
+
+        self.wake_waiters(state);
+    }
+
+    #[cold]
+    fn read_contended(&self) {
+        let mut state = self.spin_read();
+
+        loop {
+            // If we can lock it, lock it.
+            if is_read_lockable(state) {
+                match self.state.compare_exchange_weak(state, state + READ_LOCKED, Acquire, Relaxed)
+                {
+                    Ok(_) => return, // Locked!
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Check for overflow.
+            if has_reached_max_readers(state) {
+                panic!("too many active read locks on RwLock");
+            }
+
+            // Make sure the readers waiting bit is set before we go to sleep.
+            if !has_readers_waiting(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | READERS_WAITING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            // Wait for the state to change.
+            futex_wait(&self.state, state | READERS_WAITING, None);
+
+            // Spin again after waking up.
+            state = self.spin_read();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn try_write(&self) -> bool {
+        let mut state = self.state.load(Relaxed);
+        while is_unlocked(state) {
+            match self.state.compare_exchange_weak(state, state + WRITE_LOCKED, Acquire, Relaxed)
+            {
+                Ok(_) => return true,
+                Err(s) => state = s,
+            }
+        }
+        false
+    }
+
+    #[inline]
+    pub unsafe fn write(&self) {
+        if self.state.compare_exchange_weak(0, WRITE_LOCKED, Acquire, Relaxed).is_err() {
+            self.write_contended();
+        }
+    }
+
+    #[inline]
+    pub unsafe fn write_unlock(&self) {
+        let state = self.state.fetch_sub(WRITE_LOCKED, Release) - WRITE_LOCKED;
+
+        debug_assert!(is_unlocked(state));
+
+        self.wake_waiters(state);
+    }
+
+    #[cold]
+    fn write_contended(&self) {
+        let mut state = self.spin_write();
+
+        loop {
+            // If it's unlocked, we try to lock it. Any waiting flags are left
+            // in place, so our own unlock will wake up the other waiters.
+            if is_unlocked(state) {
+                match self.state.compare_exchange_weak(
+                    state,
+                    state | WRITE_LOCKED,
+                    Acquire,
+                    Relaxed,
+                ) {
+                    Ok(_) => return, // Locked!
+                    Err(s) => {
+                        state = s;
+                        continue;
+                    }
+                }
+            }
+
+            // Set the waiting bit indicating that we're waiting on it.
+            if !has_writers_waiting(state) {
+                if let Err(s) =
+                    self.state.compare_exchange(state, state | WRITERS_WAITING, Relaxed, Relaxed)
+                {
+                    state = s;
+                    continue;
+                }
+            }
+
+            // Wait for the state to change.
+            futex_wait(&self.state, state | WRITERS_WAITING, None);
+
+            // Spin again after waking up.
+            state = self.spin_write();
+        }
+    }
+
+    /// Wakes up all waiting threads if the lock has just become unlocked and
+    /// there are threads waiting on it.
+    ///
+    /// Only the thread that leaves the lock unlocked clears the waiting flags,
+    /// and a thread can only go to sleep on a state that includes its own
+    /// waiting flag, so no thread can be left sleeping on an unlocked lock.
+    #[inline]
+    fn wake_waiters(&self, mut state: u32) {
+        while is_unlocked(state) && (has_readers_waiting(state) || has_writers_waiting(state)) {
+            match self.state.compare_exchange(state, 0, Relaxed, Relaxed) {
+                Ok(_) => {
+                    futex_wake_all(&self.state);
+                    return;
+                }
+                Err(s) => state = s,
+            }
+        }
+    }
+
+    /// Spin for a while, but stop directly at the given condition.
+    fn spin_until(&self, f: impl Fn(u32) -> bool) -> u32 {
+        let mut spin = 100; // Chosen by fair dice roll.
+        loop {
+            let state = self.state.load(Relaxed);
+            if f(state) || spin == 0 {
+                return state;
+            }
+            spin_loop_hint();
+            spin -= 1;
+        }
+    }
+
+    fn spin_write(&self) -> u32 {
+        // Stop spinning when it's unlocked or when there's waiting writers,
+        // to keep things somewhat fair.
+        self.spin_until(|state| is_unlocked(state) || has_writers_waiting(state))
+    }
+
+    fn spin_read(&self) -> u32 {
+        // Stop spinning when it's unlocked or read locked,
+        // or when there's waiting threads.
+        self.spin_until(|state| {
+            !is_write_locked(state) || has_readers_waiting(state) || has_writers_waiting(state)
+        })
+    }
+
+    #[inline]
+    pub unsafe fn destroy(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RWLock;
+    use crate::cell::UnsafeCell;
+    use crate::sync::Arc;
+    use crate::thread;
+
+    /// The pthread rwlock used on other Unix platforms, as a baseline.
+    struct PthreadRWLock(UnsafeCell<libc::pthread_rwlock_t>);
+
+    unsafe impl Send for PthreadRWLock {}
+    unsafe impl Sync for PthreadRWLock {}
+
+    trait RawRWLock: Send + Sync + 'static {
+        fn read_and_unlock(&self);
+        fn write_and_unlock(&self);
+    }
+
+    impl RawRWLock for RWLock {
+        fn read_and_unlock(&self) {
+            unsafe {
+                self.read();
+                self.read_unlock();
+            }
+        }
+        fn write_and_unlock(&self) {
+            unsafe {
+                self.write();
+                self.write_unlock();
+            }
+        }
+    }
+
+    impl RawRWLock for PthreadRWLock {
+        fn read_and_unlock(&self) {
+            unsafe {
+                libc::pthread_rwlock_rdlock(self.0.get());
+                libc::pthread_rwlock_unlock(self.0.get());
+            }
+        }
+        fn write_and_unlock(&self) {
+            unsafe {
+                libc::pthread_rwlock_wrlock(self.0.get());
+                libc::pthread_rwlock_unlock(self.0.get());
+            }
+        }
+    }
+
+    fn pthread_rwlock() -> PthreadRWLock {
+        PthreadRWLock(UnsafeCell::new(libc::PTHREAD_RWLOCK_INITIALIZER))
+    }
+
+    // Three readers and one writer each take and release the lock a thousand
+    // times.
+    fn contended<L: RawRWLock>(b: &mut test::Bencher, lock: L) {
+        let lock = Arc::new(lock);
+        b.iter(|| {
+            let threads: Vec<_> = (0..4)
+                .map(|i| {
+                    let lock = lock.clone();
+                    thread::spawn(move || {
+                        for _ in 0..1000 {
+                            if i == 0 { lock.write_and_unlock() } else { lock.read_and_unlock() }
+                        }
+                    })
+                })
+                .collect();
+            for t in threads {
+                t.join().unwrap();
+            }
+        });
+    }
+
+    #[test]
+    fn readers_exclude_writers() {
+        let l = RWLock::new();
+        unsafe {
+            l.read();
+            assert!(l.try_read());
+            assert!(!l.try_write());
+            l.read_unlock();
+            l.read_unlock();
+            assert!(l.try_write());
+            assert!(!l.try_read());
+            l.write_unlock();
+        }
+    }
+
+    #[bench]
+    fn bench_uncontended_read_futex(b: &mut test::Bencher) {
+        let l = RWLock::new();
+        b.iter(|| l.read_and_unlock());
+    }
+
+    #[bench]
+    fn bench_uncontended_read_pthread(b: &mut test::Bencher) {
+        let l = pthread_rwlock();
+        b.iter(|| l.read_and_unlock());
+    }
+
+    #[bench]
+    fn bench_contended_futex(b: &mut test::Bencher) {
+        contended(b, RWLock::new());
+    }
+
+    #[bench]
+    fn bench_contended_pthread(b: &mut test::Bencher) {
+        contended(b, pthread_rwlock());
+    }
+}
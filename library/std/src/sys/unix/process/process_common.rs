@@ -4,16 +4,18 @@ use crate::collections::BTreeMap;
 use crate::ffi::{CStr, CString, OsStr, OsString};
 use crate::fmt;
 use crate::io;
+use crate::path::Path;
 use crate::ptr;
+use crate::str;
 use crate::sys::fd::FileDesc;
 use crate::sys::fs::File;
 use crate::sys::pipe::{self, AnonPipe};
-use crate::sys_common::process::CommandEnv;
+use crate::sys_common::process::{CommandEnv, CommandEnvs};
 
 #[cfg(not(target_os = "fuchsia"))]
 use crate::sys::fs::OpenOptions;
 
-use libc::{c_char, c_int, gid_t, uid_t, EXIT_FAILURE, EXIT_SUCCESS};
+use libc::{c_char, c_int, gid_t, pid_t, uid_t, EXIT_FAILURE, EXIT_SUCCESS};
 
 cfg_if::cfg_if! {
     if #[cfg(target_os = "fuchsia")] {
@@ -84,6 +86,10 @@ pub struct Command {
     stdin: Option<Stdio>,
     stdout: Option<Stdio>,
     stderr: Option<Stdio>,
+    pgroup: Option<pid_t>,
+    setsid: bool,
+    #[cfg(target_os = "linux")]
+    create_pidfd: bool,
 }
 
 // Create a new type for argv, so that we can make it `Send` and `Sync`
@@ -145,6 +151,10 @@ impl Command {
             stdin: None,
             stdout: None,
             stderr: None,
+            pgroup: None,
+            setsid: false,
+            #[cfg(target_os = "linux")]
+            create_pidfd: false,
         }
     }
 
@@ -177,6 +187,16 @@ impl Command {
     pub fn gid(&mut self, id: gid_t) {
         self.gid = Some(id);
     }
+    pub fn pgroup(&mut self, pgroup: pid_t) {
+        self.pgroup = Some(pgroup);
+    }
+    pub fn setsid(&mut self, setsid: bool) {
+        self.setsid = setsid;
+    }
+    #[cfg(target_os = "linux")]
+    pub fn create_pidfd(&mut self, val: bool) {
+        self.create_pidfd = val;
+    }
 
     pub fn saw_nul(&self) -> bool {
         self.saw_nul
@@ -185,10 +205,32 @@ impl Command {
         &self.argv.0
     }
 
-    pub fn get_program(&self) -> &CStr {
+    pub fn get_program_cstr(&self) -> &CStr {
         &*self.program
     }
 
+    pub fn get_program(&self) -> &OsStr {
+        OsStr::from_bytes(self.program.as_bytes())
+    }
+
+    pub fn get_args(&self) -> CommandArgs<'_> {
+        let mut iter = self.args.iter();
+        iter.next();
+        CommandArgs { iter }
+    }
+
+    pub fn get_envs(&self) -> CommandEnvs<'_> {
+        self.env.iter()
+    }
+
+    pub fn get_env_clear(&self) -> bool {
+        self.env.does_clear()
+    }
+
+    pub fn get_current_dir(&self) -> Option<&Path> {
+        self.cwd.as_ref().map(|cs| Path::new(OsStr::from_bytes(cs.as_bytes())))
+    }
+
     #[allow(dead_code)]
     pub fn get_cwd(&self) -> &Option<CString> {
         &self.cwd
@@ -201,6 +243,18 @@ impl Command {
     pub fn get_gid(&self) -> Option<gid_t> {
         self.gid
     }
+    #[allow(dead_code)]
+    pub fn get_pgroup(&self) -> Option<pid_t> {
+        self.pgroup
+    }
+    #[allow(dead_code)]
+    pub fn get_setsid(&self) -> bool {
+        self.setsid
+    }
+    #[cfg(target_os = "linux")]
+    pub fn get_create_pidfd(&self) -> bool {
+        self.create_pidfd
+    }
 
     pub fn get_closures(&mut self) -> &mut Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>> {
         &mut self.closures
@@ -374,19 +428,162 @@ impl ChildStdio {
 }
 
 impl fmt::Debug for Command {
+    // The default format is the command line a POSIX shell would need to run
+    // the same program in the same way: working directory and environment
+    // changes first, then the program and its arguments, quoted as needed.
+    // The alternate format shows all attributes but `closures` and `argv`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mut debug_command = f.debug_struct("Command");
+            debug_command.field("program", &self.program).field("args", &self.args);
+            if !self.env.is_unchanged() {
+                debug_command.field("env", &self.env);
+            }
+            if self.cwd.is_some() {
+                debug_command.field("cwd", &self.cwd);
+            }
+            if self.uid.is_some() {
+                debug_command.field("uid", &self.uid);
+            }
+            if self.gid.is_some() {
+                debug_command.field("gid", &self.gid);
+            }
+            if self.pgroup.is_some() {
+                debug_command.field("pgroup", &self.pgroup);
+            }
+            if self.setsid {
+                debug_command.field("setsid", &self.setsid);
+            }
+            #[cfg(target_os = "linux")]
+            {
+                if self.create_pidfd {
+                    debug_command.field("create_pidfd", &self.create_pidfd);
+                }
+            }
+            return debug_command.finish();
+        }
+
+        if let Some(ref cwd) = self.cwd {
+            write!(f, "cd {} && ", ShellQuoted(cwd.as_bytes()))?;
+        }
+        // Only names of shell variables can be set with an assignment in front
+        // of the command; other keys are passed to the `env` utility as a
+        // quoted `KEY=VALUE` argument instead.
+        let is_name = |key: &[u8]| {
+            !key.is_empty()
+                && !key[0].is_ascii_digit()
+                && key.iter().all(|&b| b.is_ascii_alphanumeric() || b == b'_')
+        };
+        if self.env.does_clear() {
+            f.write_str("env -i ")?;
+        } else {
+            // Removing variables or setting ones with unusual names needs the
+            // `env` utility, setting others doesn't.
+            let needs_env =
+                self.get_envs().any(|(key, value)| value.is_none() || !is_name(key.as_bytes()));
+            if needs_env {
+                f.write_str("env ")?;
+            }
+            for (key, value) in self.get_envs() {
+                if value.is_none() {
+                    write!(f, "-u {} ", ShellQuoted(key.as_bytes()))?;
+                }
+            }
+        }
+        for (key, value) in self.get_envs() {
+            if let Some(value) = value {
+                if is_name(key.as_bytes()) {
+                    let key = str::from_utf8(key.as_bytes()).unwrap();
+                    write!(f, "{}={} ", key, ShellQuoted(value.as_bytes()))?;
+                } else {
+                    let mut assignment = key.as_bytes().to_vec();
+                    assignment.push(b'=');
+                    assignment.extend_from_slice(value.as_bytes());
+                    write!(f, "{} ", ShellQuoted(&assignment))?;
+                }
+            }
+        }
+
+        // There's no portable shell syntax for a custom `argv[0]`.
         if self.program != self.args[0] {
-            write!(f, "[{:?}] ", self.program)?;
+            write!(f, "[{}] ", ShellQuoted(self.program.as_bytes()))?;
         }
-        write!(f, "{:?}", self.args[0])?;
+        write!(f, "{}", ShellQuoted(self.args[0].as_bytes()))?;
 
         for arg in &self.args[1..] {
-            write!(f, " {:?}", arg)?;
+            write!(f, " {}", ShellQuoted(arg.as_bytes()))?;
         }
         Ok(())
     }
 }
 
+// Quotes a word for a POSIX shell, so that the shell reads it back as a
+// single word with exactly these contents. Words made only of characters
+// that are never special to the shell are left as is, other UTF-8 words are
+// single-quoted. Non-UTF-8 words are written as `$'...'` with `\xNN` escapes,
+// which isn't POSIX yet but is understood by bash, zsh, ksh and busybox sh.
+struct ShellQuoted<'a>(&'a [u8]);
+
+impl fmt::Display for ShellQuoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let word = match str::from_utf8(self.0) {
+            Ok(word) => word,
+            Err(_) => {
+                f.write_str("$'")?;
+                for &b in self.0 {
+                    match b {
+                        b'\\' | b'\'' => write!(f, "\\{}", b as char)?,
+                        b' '..=b'~' => write!(f, "{}", b as char)?,
+                        _ => write!(f, "\\x{:02x}", b)?,
+                    }
+                }
+                return f.write_str("'");
+            }
+        };
+        let is_plain = |c: char| c.is_ascii_alphanumeric() || "%+,-./:@_".contains(c);
+        if !word.is_empty() && word.chars().all(is_plain) {
+            return f.write_str(word);
+        }
+
+        // A single quote can't appear within single quotes, so close the
+        // quoted part, add an escaped quote and open a new quoted part.
+        f.write_str("'")?;
+        for (i, part) in word.split('\'').enumerate() {
+            if i > 0 {
+                f.write_str("'\\''")?;
+            }
+            f.write_str(part)?;
+        }
+        f.write_str("'")
+    }
+}
+
+pub struct CommandArgs<'a> {
+    iter: crate::slice::Iter<'a, CString>,
+}
+
+impl<'a> Iterator for CommandArgs<'a> {
+    type Item = &'a OsStr;
+    fn next(&mut self) -> Option<&'a OsStr> {
+        self.iter.next().map(|cs| OsStr::from_bytes(cs.as_bytes()))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for CommandArgs<'a> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a> fmt::Debug for CommandArgs<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.clone()).finish()
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct ExitCode(u8);
 
@@ -400,6 +597,12 @@ impl ExitCode {
     }
 }
 
+impl From<u8> for ExitCode {
+    fn from(code: u8) -> Self {
+        Self(code)
+    }
+}
+
 #[cfg(all(test, not(target_os = "emscripten")))]
 mod tests {
     use super::*;
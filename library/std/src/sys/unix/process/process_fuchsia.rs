@@ -119,7 +119,7 @@ impl Command {
                 | FDIO_SPAWN_CLONE_LDSVC
                 | FDIO_SPAWN_CLONE_NAMESPACE
                 | FDIO_SPAWN_CLONE_ENVIRON, // this is ignored when envp is non-null
-            self.get_program().as_ptr(),
+            self.get_program_cstr().as_ptr(),
             self.get_argv().as_ptr(),
             envp,
             actions.len() as size_t,
@@ -146,6 +146,10 @@ impl Process {
         self.handle.raw() as u32
     }
 
+    pub fn send_signal(&self, _signal: i32) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "signals are not supported on Fuchsia"))
+    }
+
     pub fn kill(&mut self) -> io::Result<()> {
         use crate::sys::process::zircon::*;
 
@@ -244,6 +248,14 @@ impl ExitStatus {
     pub fn signal(&self) -> Option<i32> {
         None
     }
+
+    pub fn core_dumped(&self) -> bool {
+        false
+    }
+
+    pub fn stopped_signal(&self) -> Option<i32> {
+        None
+    }
 }
 
 /// Converts a raw `c_int` to a type-safe `ExitStatus` by wrapping it without copying.
@@ -6,8 +6,22 @@ use crate::sys;
 use crate::sys::cvt;
 use crate::sys::process::process_common::*;
 
+#[cfg(target_os = "linux")]
+use crate::os::linux::process::PidFd;
+
 use libc::{c_int, gid_t, pid_t, uid_t};
 
+// The libc crate std depends on may not define these yet. Every syscall added
+// since Linux 5.1 has the same number on all architectures, so we can spell
+// them out here. Kernels that are too old return `ENOSYS`, which the callers
+// handle.
+#[cfg(target_os = "linux")]
+const SYS_PIDFD_SEND_SIGNAL: libc::c_long = 424;
+#[cfg(target_os = "linux")]
+const SYS_PIDFD_OPEN: libc::c_long = 434;
+#[cfg(target_os = "linux")]
+const SYS_CLONE3: libc::c_long = 435;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Command
 ////////////////////////////////////////////////////////////////////////////////
@@ -42,13 +56,13 @@ impl Command {
         // Note that as soon as we're done with the fork there's no need to hold
         // a lock any more because the parent won't do anything and the child is
         // in its own process.
-        let result = unsafe {
+        let (pid, pidfd) = unsafe {
             let _env_lock = sys::os::env_lock();
-            cvt(libc::fork())?
+            self.do_fork()?
         };
 
         let pid = unsafe {
-            match result {
+            match pid {
                 0 => {
                     drop(input);
                     let Err(err) = self.do_exec(theirs, envp.as_ref());
@@ -74,7 +88,7 @@ impl Command {
             }
         };
 
-        let mut p = Process { pid, status: None };
+        let mut p = unsafe { Process::new(pid, pidfd) };
         drop(output);
         let mut bytes = [0; 8];
 
@@ -107,6 +121,107 @@ impl Command {
         }
     }
 
+    // Attempts to fork the process. If successful, returns `Ok((0, -1))` in
+    // the child and `Ok((child_pid, -1))` in the parent.
+    #[cfg(not(target_os = "linux"))]
+    unsafe fn do_fork(&mut self) -> io::Result<(pid_t, c_int)> {
+        cvt(libc::fork()).map(|pid| (pid, -1))
+    }
+
+    // Attempts to fork the process. If successful, returns `Ok((0, -1))` in
+    // the child and `Ok((child_pid, child_pidfd))` in the parent, where
+    // `child_pidfd` is -1 if no pidfd was requested or one couldn't be
+    // created.
+    //
+    // A pidfd is obtained atomically with `clone3(CLONE_PIDFD)` (Linux 5.3+)
+    // when possible. If `clone3` is unavailable or blocked (e.g. by a seccomp
+    // filter) we fall back to `fork` followed by `pidfd_open` (Linux 5.3+ as
+    // well, but allowed by more sandboxes). The latter is not racy as long as
+    // the child can't be reaped until we wait on it, so that its pid can't be
+    // reused yet. That doesn't hold if `SIGCHLD` is ignored (`SIG_IGN`): the
+    // kernel then reaps exited children itself, and a child which exits right
+    // away may have had its pid reused by the time `pidfd_open` is called.
+    // `Child::kill` and `ChildExt::signal` have the same problem in that case,
+    // so this is only documented on `CommandExt::create_pidfd`.
+    #[cfg(target_os = "linux")]
+    unsafe fn do_fork(&mut self) -> io::Result<(pid_t, c_int)> {
+        use crate::sync::atomic::{AtomicBool, Ordering};
+
+        static HAS_CLONE3: AtomicBool = AtomicBool::new(true);
+        static HAS_PIDFD_OPEN: AtomicBool = AtomicBool::new(true);
+
+        const CLONE_PIDFD: u64 = 0x00001000;
+
+        // The first version of `struct clone_args`, which every kernel with
+        // `clone3` accepts.
+        #[repr(C)]
+        struct clone_args {
+            flags: u64,
+            pidfd: u64,
+            child_tid: u64,
+            parent_tid: u64,
+            exit_signal: u64,
+            stack: u64,
+            stack_size: u64,
+            tls: u64,
+        }
+
+        unsafe fn clone3(cl_args: *mut clone_args, len: libc::size_t) -> libc::c_long {
+            libc::syscall(SYS_CLONE3, cl_args as libc::c_long, len as libc::c_long)
+        }
+        unsafe fn pidfd_open(pid: pid_t, flags: libc::c_uint) -> c_int {
+            libc::syscall(SYS_PIDFD_OPEN, pid as libc::c_long, flags as libc::c_long) as c_int
+        }
+
+        if !self.get_create_pidfd() {
+            return cvt(libc::fork()).map(|pid| (pid, -1));
+        }
+
+        // Bypassing libc for `clone3` means that `pthread_atfork` handlers
+        // don't run and libc's view of the calling thread isn't updated in
+        // the child, so we only use it when a pidfd was asked for. The child
+        // only runs `do_exec`, which is careful about what it calls anyway.
+        let mut pidfd: c_int = -1;
+        if HAS_CLONE3.load(Ordering::Relaxed) {
+            let mut args = clone_args {
+                flags: CLONE_PIDFD,
+                pidfd: &mut pidfd as *mut c_int as u64,
+                child_tid: 0,
+                parent_tid: 0,
+                exit_signal: libc::SIGCHLD as u64,
+                stack: 0,
+                stack_size: 0,
+                tls: 0,
+            };
+            match cvt(clone3(&mut args, crate::mem::size_of::<clone_args>())) {
+                Ok(pid) => return Ok((pid as pid_t, pidfd)),
+                Err(e) => match e.raw_os_error() {
+                    // Several threads may race to store this, which is fine:
+                    // they all tried the same syscall and it failed the same
+                    // way for each of them.
+                    Some(libc::ENOSYS) => HAS_CLONE3.store(false, Ordering::Relaxed),
+                    // Blocked by a seccomp filter or similar, try `fork`.
+                    Some(libc::EPERM) => {}
+                    _ => return Err(e),
+                },
+            }
+        }
+
+        let pid = cvt(libc::fork())?;
+        if pid != 0 && HAS_PIDFD_OPEN.load(Ordering::Relaxed) {
+            match cvt(pidfd_open(pid, 0)) {
+                Ok(fd) => pidfd = fd,
+                Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {
+                    HAS_PIDFD_OPEN.store(false, Ordering::Relaxed)
+                }
+                // Not being able to create a pidfd isn't a reason to fail
+                // the spawn; `ChildExt::pidfd` reports the error instead.
+                Err(_) => {}
+            }
+        }
+        Ok((pid, pidfd))
+    }
+
     pub fn exec(&mut self, default: Stdio) -> io::Error {
         let envp = self.capture_env();
 
@@ -200,6 +315,13 @@ impl Command {
             cvt(libc::chdir(cwd.as_ptr()))?;
         }
 
+        if let Some(pgroup) = self.get_pgroup() {
+            cvt(libc::setpgid(0, pgroup))?;
+        }
+        if self.get_setsid() {
+            cvt(libc::setsid())?;
+        }
+
         // emscripten has no signal support.
         #[cfg(not(target_os = "emscripten"))]
         {
@@ -245,7 +367,7 @@ impl Command {
             *sys::os::environ() = envp.as_ptr();
         }
 
-        libc::execvp(self.get_program().as_ptr(), self.get_argv().as_ptr());
+        libc::execvp(self.get_program_cstr().as_ptr(), self.get_argv().as_ptr());
         Err(io::Error::last_os_error())
     }
 
@@ -279,12 +401,21 @@ impl Command {
 
         if self.get_gid().is_some()
             || self.get_uid().is_some()
+            || self.get_setsid()
             || self.env_saw_path()
             || !self.get_closures().is_empty()
         {
             return Ok(None);
         }
 
+        // `posix_spawn` can't hand us a pidfd, leave that to `do_fork`.
+        #[cfg(target_os = "linux")]
+        {
+            if self.get_create_pidfd() {
+                return Ok(None);
+            }
+        }
+
         // Only glibc 2.24+ posix_spawn() supports returning ENOENT directly.
         #[cfg(all(target_os = "linux", target_env = "gnu"))]
         {
@@ -315,7 +446,7 @@ impl Command {
             None => None,
         };
 
-        let mut p = Process { pid: 0, status: None };
+        let mut pid = 0;
 
         struct PosixSpawnFileActions(MaybeUninit<libc::posix_spawn_file_actions_t>);
 
@@ -375,21 +506,29 @@ impl Command {
             cvt(sigaddset(set.as_mut_ptr(), libc::SIGPIPE))?;
             cvt(libc::posix_spawnattr_setsigdefault(attrs.0.as_mut_ptr(), set.as_ptr()))?;
 
-            let flags = libc::POSIX_SPAWN_SETSIGDEF | libc::POSIX_SPAWN_SETSIGMASK;
+            let mut flags = libc::POSIX_SPAWN_SETSIGDEF | libc::POSIX_SPAWN_SETSIGMASK;
+            if let Some(pgroup) = self.get_pgroup() {
+                flags |= libc::POSIX_SPAWN_SETPGROUP;
+                cvt(libc::posix_spawnattr_setpgroup(attrs.0.as_mut_ptr(), pgroup))?;
+            }
             cvt(libc::posix_spawnattr_setflags(attrs.0.as_mut_ptr(), flags as _))?;
 
             // Make sure we synchronize access to the global `environ` resource
             let _env_lock = sys::os::env_lock();
             let envp = envp.map(|c| c.as_ptr()).unwrap_or_else(|| *sys::os::environ() as *const _);
             let ret = libc::posix_spawnp(
-                &mut p.pid,
-                self.get_program().as_ptr(),
+                &mut pid,
+                self.get_program_cstr().as_ptr(),
                 file_actions.0.as_ptr(),
                 attrs.0.as_ptr(),
                 self.get_argv().as_ptr() as *const _,
                 envp as *const _,
             );
-            if ret == 0 { Ok(Some(p)) } else { Err(io::Error::from_raw_os_error(ret)) }
+            if ret == 0 {
+                Ok(Some(Process::new(pid, -1)))
+            } else {
+                Err(io::Error::from_raw_os_error(ret))
+            }
         }
     }
 }
@@ -402,13 +541,84 @@ impl Command {
 pub struct Process {
     pid: pid_t,
     status: Option<ExitStatus>,
+    // The pidfd of the child, if one was requested with
+    // `CommandExt::create_pidfd` and could be created.
+    #[cfg(target_os = "linux")]
+    pidfd: Option<PidFd>,
 }
 
 impl Process {
+    // `pidfd` must be -1 or a file descriptor that the returned `Process`
+    // takes ownership of.
+    #[cfg(target_os = "linux")]
+    unsafe fn new(pid: pid_t, pidfd: c_int) -> Process {
+        use crate::sys::fd::FileDesc;
+        use crate::sys_common::FromInner;
+
+        let pidfd = if pidfd >= 0 { Some(PidFd::from_inner(FileDesc::new(pidfd))) } else { None };
+        Process { pid, status: None, pidfd }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    unsafe fn new(pid: pid_t, _pidfd: c_int) -> Process {
+        Process { pid, status: None }
+    }
+
     pub fn id(&self) -> u32 {
         self.pid as u32
     }
 
+    pub fn send_signal(&self, signal: i32) -> io::Result<()> {
+        // Same as for `kill`, the pid may have been recycled once we've
+        // reaped the child.
+        if self.status.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid argument: can't signal an exited process",
+            ));
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            use crate::sys_common::AsInner;
+
+            unsafe fn pidfd_send_signal(
+                pidfd: c_int,
+                sig: c_int,
+                info: *mut libc::siginfo_t,
+                flags: libc::c_uint,
+            ) -> c_int {
+                let (pidfd, sig) = (pidfd as libc::c_long, sig as libc::c_long);
+                let (info, flags) = (info as libc::c_long, flags as libc::c_long);
+                libc::syscall(SYS_PIDFD_SEND_SIGNAL, pidfd, sig, info, flags) as c_int
+            }
+
+            if let Some(ref pidfd) = self.pidfd {
+                let fd = pidfd.as_inner().raw();
+                match cvt(unsafe { pidfd_send_signal(fd, signal, ptr::null_mut(), 0) }) {
+                    // A seccomp filter may hide the syscall even though the
+                    // kernel handed us a pidfd; the child can't have been
+                    // reaped yet (unless `SIGCHLD` is ignored, see
+                    // `do_fork`), so `kill` is still safe to use.
+                    Err(ref e) if e.raw_os_error() == Some(libc::ENOSYS) => {}
+                    r => return r.map(drop),
+                }
+            }
+        }
+
+        cvt(unsafe { libc::kill(self.pid, signal) }).map(drop)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn pidfd(&self) -> Option<&PidFd> {
+        self.pidfd.as_ref()
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn take_pidfd(&mut self) -> Option<PidFd> {
+        self.pidfd.take()
+    }
+
     pub fn kill(&mut self) -> io::Result<()> {
         // If we've already waited on this process then the pid can be recycled
         // and used for another process, and we probably shouldn't be killing
@@ -471,7 +681,23 @@ impl ExitStatus {
     }
 
     pub fn signal(&self) -> Option<i32> {
-        if !self.exited() { Some(unsafe { libc::WTERMSIG(self.0) }) } else { None }
+        if unsafe { libc::WIFSIGNALED(self.0) } {
+            Some(unsafe { libc::WTERMSIG(self.0) })
+        } else {
+            None
+        }
+    }
+
+    pub fn core_dumped(&self) -> bool {
+        unsafe { libc::WIFSIGNALED(self.0) && libc::WCOREDUMP(self.0) }
+    }
+
+    pub fn stopped_signal(&self) -> Option<i32> {
+        if unsafe { libc::WIFSTOPPED(self.0) } {
+            Some(unsafe { libc::WSTOPSIG(self.0) })
+        } else {
+            None
+        }
     }
 }
 
@@ -486,9 +712,16 @@ impl fmt::Display for ExitStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(code) = self.code() {
             write!(f, "exit code: {}", code)
+        } else if let Some(signal) = self.signal() {
+            if self.core_dumped() {
+                write!(f, "signal: {} (core dumped)", signal)
+            } else {
+                write!(f, "signal: {}", signal)
+            }
+        } else if let Some(signal) = self.stopped_signal() {
+            write!(f, "stopped (not terminated) by signal: {}", signal)
         } else {
-            let signal = self.signal().unwrap();
-            write!(f, "signal: {}", signal)
+            write!(f, "unrecognised wait status: {} {:#x}", self.0, self.0)
         }
     }
 }
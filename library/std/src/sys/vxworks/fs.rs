@@ -1,7 +1,7 @@
 // copies from linuxx
 use crate::ffi::{CStr, CString, OsStr, OsString};
 use crate::fmt;
-use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut, ReadBuf, SeekFrom};
 use crate::mem;
 use crate::path::{Path, PathBuf};
 use crate::ptr;
@@ -347,6 +347,10 @@ impl File {
         self.0.read(buf)
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        crate::io::default_read_buf(|buf| self.read(buf), buf)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -410,6 +414,27 @@ impl File {
         Ok(())
     }
 
+    // VxWorks does not provide `flock`.
+    pub fn lock_shared(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        Err(lock_unsupported())
+    }
+
     pub fn diverge(&self) -> ! {
         panic!()
     }
@@ -623,3 +648,7 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     writer.set_permissions(perm)?;
     Ok(ret)
 }
+
+fn lock_unsupported() -> io::Error {
+    Error::new(ErrorKind::Other, "file locking is not supported on this platform")
+}
@@ -35,6 +35,7 @@ pub use crate::sys_common::os_str_bytes as os_str;
 pub mod ext;
 pub mod path;
 pub mod pipe;
+#[path = "../unsupported/process.rs"]
 pub mod process;
 #[path = "../unsupported/rwlock.rs"]
 pub mod rwlock;
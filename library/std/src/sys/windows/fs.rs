@@ -2,7 +2,7 @@ use crate::os::windows::prelude::*;
 
 use crate::ffi::OsString;
 use crate::fmt;
-use crate::io::{self, Error, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{self, Error, IoSlice, IoSliceMut, ReadBuf, SeekFrom};
 use crate::mem;
 use crate::path::{Path, PathBuf};
 use crate::ptr;
@@ -405,6 +405,10 @@ impl File {
         self.handle.read(buf)
     }
 
+    pub fn read_buf(&self, buf: &mut ReadBuf<'_>) -> io::Result<()> {
+        self.handle.read_buf(buf)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.handle.read_vectored(bufs)
     }
@@ -549,6 +553,59 @@ impl File {
         })?;
         Ok(())
     }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file(0)
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        // A handle may hold both a shared and an exclusive lock on the same
+        // range, in which case two unlock operations are needed: the first
+        // releases the exclusive lock and the second the shared one. Unlocking
+        // a file that isn't locked is not an error.
+        for _ in 0..2 {
+            match cvt(unsafe {
+                c::UnlockFile(self.handle.raw(), 0, 0, c::DWORD::MAX, c::DWORD::MAX)
+            }) {
+                Err(ref e) if e.raw_os_error() == Some(c::ERROR_NOT_LOCKED as i32) => break,
+                r => r.map(drop)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Locks the whole file, matching the semantics of `flock` on Unix.
+    fn lock_file(&self, flags: c::DWORD) -> io::Result<()> {
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        let r = cvt(unsafe {
+            c::LockFileEx(
+                self.handle.raw(),
+                flags,
+                0,
+                c::DWORD::MAX,
+                c::DWORD::MAX,
+                &mut overlapped,
+            )
+        });
+        match r {
+            Err(ref e) if e.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => {
+                Err(io::Error::new(io::ErrorKind::WouldBlock, "the file is locked"))
+            }
+            r => r.map(drop),
+        }
+    }
 }
 
 impl FromInner<c::HANDLE> for File {
@@ -79,6 +79,14 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        pub use crate::sys::once;
+    } else {
+        pub mod once;
+    }
+}
+
 // common error constructors
 
 /// A trait for viewing representations from std types
@@ -123,6 +131,8 @@ pub fn at_exit<F: FnOnce() + Send + 'static>(f: F) -> Result<(), ()> {
 pub fn cleanup() {
     static CLEANUP: Once = Once::new();
     CLEANUP.call_once(|| unsafe {
+        // Flush stdout and disable buffering.
+        crate::io::cleanup();
         sys::args::cleanup();
         sys::stack_overflow::cleanup();
         at_exit_imp::cleanup();
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 #![unstable(feature = "process_internals", issue = "none")]
 
+use crate::collections::btree_map;
 use crate::collections::BTreeMap;
 use crate::env;
 use crate::ffi::{OsStr, OsString};
@@ -87,9 +88,47 @@ impl CommandEnv {
         self.saw_path || self.clear
     }
 
+    pub fn does_clear(&self) -> bool {
+        self.clear
+    }
+
+    pub fn iter(&self) -> CommandEnvs<'_> {
+        CommandEnvs { iter: self.vars.iter() }
+    }
+
     fn maybe_saw_path(&mut self, key: &OsStr) {
         if !self.saw_path && key == "PATH" {
             self.saw_path = true;
         }
     }
 }
+
+/// An iterator over the command environment variables.
+///
+/// This struct is created by [`Command::get_envs`]. See its documentation for
+/// more.
+///
+/// [`Command::get_envs`]: struct.Command.html#method.get_envs
+#[unstable(feature = "command_access", issue = "none")]
+#[derive(Debug)]
+pub struct CommandEnvs<'a> {
+    iter: btree_map::Iter<'a, EnvKey, Option<OsString>>,
+}
+
+#[unstable(feature = "command_access", issue = "none")]
+impl<'a> Iterator for CommandEnvs<'a> {
+    type Item = (&'a OsStr, Option<&'a OsStr>);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (key.as_ref(), value.as_deref()))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "command_access", issue = "none")]
+impl<'a> ExactSizeIterator for CommandEnvs<'a> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -385,8 +385,8 @@ pub fn run_compiler(
 
             queries.ongoing_codegen()?;
 
-            if sess.opts.debugging_opts.print_type_sizes {
-                sess.code_stats.print_type_sizes();
+            if let Some(format) = sess.opts.debugging_opts.print_type_sizes {
+                sess.code_stats.print_type_sizes(format);
             }
 
             let linker = queries.linker()?;
@@ -615,8 +615,14 @@ impl RustcDefaultCalls {
                 Input::File(ref ifile) => {
                     let path = &(*ifile);
                     let mut v = Vec::new();
-                    locator::list_file_metadata(&sess.target.target, path, metadata_loader, &mut v)
-                        .unwrap();
+                    locator::list_file_metadata(
+                        &sess.target.target,
+                        path,
+                        metadata_loader,
+                        &mut v,
+                        sess.opts.debugging_opts.ls_json,
+                    )
+                    .unwrap();
                     println!("{}", String::from_utf8(v).unwrap());
                 }
                 Input::Str { .. } => {
@@ -579,6 +579,9 @@ declare_features! (
     /// Alloc calling `transmute` in const fn
     (active, const_fn_transmute, "1.46.0", Some(53605), None),
 
+    /// Allows `#[assert_layout(size = N, align = N)]` to check the layout of a type at compile time.
+    (active, assert_layout, "1.47.0", None, None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
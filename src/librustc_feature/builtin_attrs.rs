@@ -342,6 +342,10 @@ pub const BUILTIN_ATTRIBUTES: &[BuiltinAttribute] = &[
         register_tool, CrateLevel, template!(List: "tool1, tool2, ..."),
         experimental!(register_tool),
     ),
+    gated!(
+        assert_layout, AssumedUsed, template!(List: "size = N, align = N"),
+        experimental!(assert_layout),
+    ),
 
     // ==========================================================================
     // Internal attributes: Stability, deprecation, and unsafe:
@@ -585,21 +585,21 @@ fn write_out_deps(
             .map(|fmap| escape_dep_filename(&fmap.unmapped_path.as_ref().unwrap_or(&fmap.name)))
             .collect();
 
+        // The interface hash of each crate file we depend on, so that build tools
+        // can tell whether a changed dependency requires recompiling this crate.
+        let mut interface_hashes = Vec::new();
+
         if sess.binary_dep_depinfo() {
             boxed_resolver.borrow().borrow_mut().access(|resolver| {
                 for cnum in resolver.cstore().crates_untracked() {
                     let source = resolver.cstore().crate_source_untracked(cnum);
-                    if let Some((path, _)) = source.dylib {
-                        let file_name = FileName::Real(RealFileName::Named(path));
-                        files.push(escape_dep_filename(&file_name));
-                    }
-                    if let Some((path, _)) = source.rlib {
-                        let file_name = FileName::Real(RealFileName::Named(path));
-                        files.push(escape_dep_filename(&file_name));
-                    }
-                    if let Some((path, _)) = source.rmeta {
+                    let interface_hash = resolver.cstore().crate_interface_hash_untracked(cnum);
+                    let paths = source.dylib.into_iter().chain(source.rlib).chain(source.rmeta);
+                    for (path, _) in paths {
                         let file_name = FileName::Real(RealFileName::Named(path));
-                        files.push(escape_dep_filename(&file_name));
+                        let file_name = escape_dep_filename(&file_name);
+                        interface_hashes.push((file_name.clone(), interface_hash));
+                        files.push(file_name);
                     }
                 }
             });
@@ -635,6 +635,14 @@ fn write_out_deps(
             }
         }
 
+        // Emit special comments with the interface hashes of the crates we depend on.
+        if !interface_hashes.is_empty() {
+            writeln!(file)?;
+            for (path, interface_hash) in interface_hashes {
+                writeln!(file, "# interface-hash:{}={}", path, interface_hash)?;
+            }
+        }
+
         Ok(())
     })();
 
@@ -27,6 +27,7 @@ use rustc_span::source_map::FileLoader;
 use rustc_span::symbol::{sym, Symbol};
 use smallvec::SmallVec;
 use std::env;
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
 use std::io::{self, Write};
 use std::mem;
 use std::ops::DerefMut;
@@ -238,7 +239,8 @@ pub fn get_codegen_backend(sess: &Session) -> Box<dyn CodegenBackend> {
         let codegen_name = sess.opts.debugging_opts.codegen_backend.as_deref().unwrap_or("llvm");
         let backend = match codegen_name {
             filename if filename.contains('.') => load_backend_from_dylib(filename.as_ref()),
-            codegen_name => get_builtin_codegen_backend(codegen_name),
+            "llvm" => get_builtin_codegen_backend(codegen_name),
+            codegen_name => get_codegen_sysroot(sess, codegen_name),
         };
 
         unsafe {
@@ -366,6 +368,90 @@ fn sysroot_candidates() -> Vec<PathBuf> {
     }
 }
 
+/// Loads the codegen backend named `backend_name` from the `codegen-backends`
+/// directory next to the target libdir of the sysroot, e.g. a backend built as
+/// `librustc_codegen_cranelift.so` is selected with `-Z codegen-backend=cranelift`.
+/// An explicit `--sysroot` is searched before the sysroot of the running compiler.
+fn get_codegen_sysroot(sess: &Session, backend_name: &str) -> fn() -> Box<dyn CodegenBackend> {
+    let target = session::config::host_triple();
+    let mut sysroot_candidates = sysroot_candidates();
+    if let Some(ref sysroot) = sess.opts.maybe_sysroot {
+        sysroot_candidates.insert(0, sysroot.clone());
+    }
+
+    let sysroot = sysroot_candidates
+        .iter()
+        .map(|sysroot| {
+            let libdir = filesearch::relative_target_lib_path(&sysroot, &target);
+            sysroot.join(libdir).with_file_name("codegen-backends")
+        })
+        .find(|f| {
+            info!("codegen backend candidate: {}", f.display());
+            f.exists()
+        });
+    let sysroot = sysroot.unwrap_or_else(|| {
+        let candidates = sysroot_candidates
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join("\n* ");
+        let err = format!(
+            "failed to find a `codegen-backends` folder in the sysroot candidates:\n* {}",
+            candidates
+        );
+        early_error(ErrorOutputType::default(), &err);
+    });
+    info!("probing {} for a codegen backend", sysroot.display());
+
+    let d = sysroot.read_dir().unwrap_or_else(|e| {
+        let err =
+            format!("failed to load codegen backend, couldn't read `{}`: {}", sysroot.display(), e);
+        early_error(ErrorOutputType::default(), &err);
+    });
+
+    let mut file: Option<PathBuf> = None;
+
+    let expected_name = format!("rustc_codegen_{}", backend_name);
+    for entry in d.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let filename = match path.file_name().and_then(|s| s.to_str()) {
+            Some(s) => s,
+            None => continue,
+        };
+        if !(filename.starts_with(DLL_PREFIX) && filename.ends_with(DLL_SUFFIX)) {
+            continue;
+        }
+        let name = &filename[DLL_PREFIX.len()..filename.len() - DLL_SUFFIX.len()];
+        if name != expected_name {
+            continue;
+        }
+        if let Some(ref prev) = file {
+            let err = format!(
+                "duplicate codegen backends found\nfirst:  {}\nsecond: {}",
+                prev.display(),
+                path.display()
+            );
+            early_error(ErrorOutputType::default(), &err);
+        }
+        file = Some(path.clone());
+    }
+
+    match file {
+        Some(ref s) => load_backend_from_dylib(s),
+        None => {
+            let err = format!(
+                "unsupported codegen backend `{}`: no `{}{}{}` in `{}`",
+                backend_name,
+                DLL_PREFIX,
+                expected_name,
+                DLL_SUFFIX,
+                sysroot.display()
+            );
+            early_error(ErrorOutputType::default(), &err);
+        }
+    }
+}
+
 pub fn get_builtin_codegen_backend(backend_name: &str) -> fn() -> Box<dyn CodegenBackend> {
     #[cfg(feature = "llvm")]
     {
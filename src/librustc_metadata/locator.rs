@@ -835,6 +835,7 @@ pub fn list_file_metadata(
     path: &Path,
     metadata_loader: &dyn MetadataLoader,
     out: &mut dyn Write,
+    json: bool,
 ) -> IoResult<()> {
     let filename = path.file_name().unwrap().to_str().unwrap();
     let flavor = if filename.ends_with(".rlib") {
@@ -845,7 +846,7 @@ pub fn list_file_metadata(
         CrateFlavor::Dylib
     };
     match get_metadata_section(target, flavor, path, metadata_loader) {
-        Ok(metadata) => metadata.list_crate_metadata(out),
+        Ok(metadata) => metadata.list_crate_metadata(out, json),
         Err(msg) => write!(out, "{}\n", msg),
     }
 }
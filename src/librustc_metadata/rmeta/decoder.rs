@@ -348,7 +348,13 @@ impl<'a, 'tcx> TyDecoder<'tcx> for DecodeContext<'a, 'tcx> {
     }
 
     fn map_encoded_cnum_to_current(&self, cnum: CrateNum) -> CrateNum {
-        if cnum == LOCAL_CRATE { self.cdata().cnum } else { self.cdata().cnum_map[cnum] }
+        // Without a `CrateMetadata` (e.g. when listing metadata with `-Z ls`),
+        // crate numbers are left as encoded, i.e. indices into `crate_deps`.
+        let cdata = match self.cdata {
+            Some(cdata) => cdata,
+            None => return cnum,
+        };
+        if cnum == LOCAL_CRATE { cdata.cnum } else { cdata.cnum_map[cnum] }
     }
 }
 
@@ -581,11 +587,149 @@ impl MetadataBlob {
         Lazy::<CrateRoot<'tcx>>::from_position(NonZeroUsize::new(pos).unwrap()).decode(self)
     }
 
-    crate fn list_crate_metadata(&self, out: &mut dyn io::Write) -> io::Result<()> {
-        write!(out, "=External Dependencies=\n")?;
+    /// Dumps the crate root, dependencies, lang items, proc macros, public items
+    /// and the sizes of the per-`DefIndex` tables, either as text or as JSON.
+    crate fn list_crate_metadata(&self, out: &mut dyn io::Write, json: bool) -> io::Result<()> {
         let root = self.get_root();
-        for (i, dep) in root.crate_deps.decode(self).enumerate() {
-            write!(out, "{} {}{}\n", i + 1, dep.name, dep.extra_filename)?;
+        let def_path_table = root.def_path_table.decode(self);
+        let def_path = |index| {
+            DefPath::make(LOCAL_CRATE, index, |i| def_path_table.def_key(i)).to_string_no_crate()
+        };
+
+        let deps: Vec<CrateDep> = root.crate_deps.decode(self).collect();
+        let lang_items: Vec<(String, String)> = root
+            .lang_items
+            .decode(self)
+            .map(|(def_index, index)| {
+                let name = match lang_items::LangItem::from_u32(index as u32) {
+                    Some(item) => item.name().to_string(),
+                    None => index.to_string(),
+                };
+                (name, def_path(def_index))
+            })
+            .collect();
+        let missing_lang_items: Vec<String> =
+            root.lang_items_missing.decode(self).map(|item| item.name().to_string()).collect();
+        let proc_macros: Vec<String> = match root.proc_macro_data {
+            Some(data) => data.decode(self).map(def_path).collect(),
+            None => Vec::new(),
+        };
+        // Items are listed in `DefIndex` order, skipping the crate root itself.
+        let items: Vec<(DefKind, String, bool)> = (1..def_path_table.size())
+            .map(DefIndex::from_usize)
+            .filter_map(|index| {
+                let kind = root.tables.kind.get(self, index)?.decode(self);
+                let visibility = root.tables.visibility.get(self, index)?.decode(self);
+                if visibility != ty::Visibility::Public {
+                    return None;
+                }
+                let has_mir = root.tables.mir.get(self, index).is_some();
+                Some((kind.def_kind(), def_path(index), has_mir))
+            })
+            .collect();
+        let table_sizes = root.tables.byte_sizes();
+
+        if json {
+            use rustc_serialize::json::{Json, ToJson};
+            use std::collections::BTreeMap;
+
+            fn object(fields: Vec<(&str, Json)>) -> Json {
+                Json::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+            }
+
+            let dump = object(vec![
+                ("name", root.name.to_string().to_json()),
+                ("hash", root.hash.to_string().to_json()),
+                ("interface_hash", root.interface_hash.to_string().to_json()),
+                ("triple", root.triple.to_string().to_json()),
+                ("disambiguator", root.disambiguator.to_string().to_json()),
+                ("edition", root.edition.to_string().to_json()),
+                ("panic_strategy", root.panic_strategy.desc().to_json()),
+                (
+                    "dependencies",
+                    Json::Array(
+                        deps.iter()
+                            .enumerate()
+                            .map(|(i, dep)| {
+                                object(vec![
+                                    ("cnum", (i + 1).to_json()),
+                                    ("name", dep.name.to_string().to_json()),
+                                    ("extra_filename", dep.extra_filename.to_json()),
+                                    ("hash", dep.hash.to_string().to_json()),
+                                    ("host_hash", dep.host_hash.map(|h| h.to_string()).to_json()),
+                                    ("kind", format!("{:?}", dep.kind).to_json()),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+                ("lang_items", lang_items.iter().cloned().collect::<BTreeMap<_, _>>().to_json()),
+                ("missing_lang_items", missing_lang_items.to_json()),
+                ("proc_macros", proc_macros.to_json()),
+                (
+                    "items",
+                    Json::Array(
+                        items
+                            .iter()
+                            .map(|(kind, path, has_mir)| {
+                                object(vec![
+                                    ("kind", format!("{:?}", kind).to_json()),
+                                    ("path", path.to_json()),
+                                    ("mir", has_mir.to_json()),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+                ("table_sizes", table_sizes.iter().cloned().collect::<BTreeMap<_, _>>().to_json()),
+            ]);
+            return write!(out, "{}\n", dump.pretty());
+        }
+
+        write!(out, "=Crate Info=\n")?;
+        write!(out, "name {}\n", root.name)?;
+        write!(out, "hash {}\n", root.hash)?;
+        write!(out, "interface hash {}\n", root.interface_hash)?;
+        write!(out, "triple {}\n", root.triple)?;
+        write!(out, "disambiguator {}\n", root.disambiguator)?;
+        write!(out, "edition {}\n", root.edition)?;
+        write!(out, "panic strategy {}\n", root.panic_strategy.desc())?;
+        write!(out, "\n")?;
+
+        write!(out, "=External Dependencies=\n")?;
+        for (i, dep) in deps.iter().enumerate() {
+            write!(out, "{} {}{} {} {:?}", i + 1, dep.name, dep.extra_filename, dep.hash, dep.kind)?;
+            if let Some(host_hash) = dep.host_hash {
+                write!(out, " host {}", host_hash)?;
+            }
+            write!(out, "\n")?;
+        }
+        write!(out, "\n")?;
+
+        write!(out, "=Lang Items=\n")?;
+        for (name, path) in &lang_items {
+            write!(out, "{} {}\n", name, path)?;
+        }
+        for name in &missing_lang_items {
+            write!(out, "{} (missing)\n", name)?;
+        }
+        write!(out, "\n")?;
+
+        write!(out, "=Proc Macros=\n")?;
+        for path in &proc_macros {
+            write!(out, "{}\n", path)?;
+        }
+        write!(out, "\n")?;
+
+        write!(out, "=Public Items=\n")?;
+        for (kind, path, has_mir) in &items {
+            write!(out, "{:?} {}{}\n", kind, path, if *has_mir { " (mir)" } else { "" })?;
+        }
+        write!(out, "\n")?;
+
+        write!(out, "=Table Sizes=\n")?;
+        for (name, size) in &table_sizes {
+            write!(out, "{} {}\n", name, size)?;
         }
         write!(out, "\n")?;
         Ok(())
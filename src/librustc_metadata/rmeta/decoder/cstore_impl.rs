@@ -190,6 +190,7 @@ provide! { <'tcx> tcx, def_id, other, cdata,
     }
     crate_disambiguator => { cdata.root.disambiguator }
     crate_hash => { cdata.root.hash }
+    crate_interface_hash => { cdata.root.interface_hash }
     crate_host_hash => { cdata.host_hash }
     original_crate_name => { cdata.root.name }
 
@@ -287,6 +288,10 @@ pub fn provide(providers: &mut Providers) {
             assert_eq!(cnum, LOCAL_CRATE);
             Lrc::new(link_args::collect(tcx))
         },
+        crate_interface_hash: |tcx, cnum| {
+            assert_eq!(cnum, LOCAL_CRATE);
+            encoder::crate_interface_hash(tcx)
+        },
 
         // Returns a map from a sufficiently visible external item (i.e., an
         // external item that is visible from at least one local module) to a
@@ -442,6 +447,10 @@ impl CStore {
         self.get_crate_data(cnum).source.clone()
     }
 
+    pub fn crate_interface_hash_untracked(&self, cnum: CrateNum) -> Svh {
+        self.get_crate_data(cnum).root.interface_hash
+    }
+
     pub fn get_span_untracked(&self, def_id: DefId, sess: &Session) -> Span {
         self.get_crate_data(def_id.krate).get_span(def_id.index, sess)
     }
@@ -6,10 +6,10 @@ use rustc_ast::ast;
 use rustc_ast::attr;
 use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use rustc_data_structures::stable_hasher::StableHasher;
+use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_data_structures::sync::{join, Lrc};
 use rustc_hir as hir;
-use rustc_hir::def::CtorKind;
+use rustc_hir::def::{CtorKind, DefKind};
 use rustc_hir::def_id::{CrateNum, DefId, DefIndex, LocalDefId, CRATE_DEF_INDEX, LOCAL_CRATE};
 use rustc_hir::definitions::DefPathTable;
 use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
@@ -640,6 +640,7 @@ impl<'a, 'tcx> EncodeContext<'a, 'tcx> {
             extra_filename: tcx.sess.opts.cg.extra_filename.clone(),
             triple: tcx.sess.opts.target_triple.clone(),
             hash: tcx.crate_hash(LOCAL_CRATE),
+            interface_hash: tcx.crate_interface_hash(LOCAL_CRATE),
             disambiguator: tcx.sess.local_crate_disambiguator(),
             panic_strategy: tcx.sess.panic_strategy(),
             edition: tcx.sess.edition(),
@@ -2028,3 +2029,199 @@ fn encode_metadata_impl(tcx: TyCtxt<'_>) -> EncodedMetadata {
 
     EncodedMetadata { raw_data: result }
 }
+
+/// Computes a hash of everything about the local crate that downstream crates can
+/// observe: the signatures and spans of reachable items, all trait impls, the MIR
+/// of items which can be inlined or const-evaluated downstream, exported symbols
+/// and macros, and the interfaces of our own dependencies. Unlike the `Svh`, this
+/// hash does not change when only the bodies of private or non-inlinable functions
+/// change.
+///
+/// This hash is only reported to build tools (see `-Z ls`), which can use it to
+/// decide whether dependent crates need to be rebuilt. Incremental compilation
+/// still uses the `Svh` as the fingerprint of our metadata.
+pub(super) fn crate_interface_hash(tcx: TyCtxt<'_>) -> Svh {
+    // The expansion of a proc macro depends on all of its code.
+    if tcx.sess.crate_types().contains(&CrateType::ProcMacro) {
+        return tcx.crate_hash(LOCAL_CRATE);
+    }
+
+    let krate = tcx.hir().krate();
+    let access_levels = tcx.privacy_access_levels(LOCAL_CRATE);
+    let reachable_set = tcx.reachable_set(LOCAL_CRATE);
+    let mir_keys = tcx.mir_keys(LOCAL_CRATE);
+    let is_interface =
+        |hir_id| access_levels.is_reachable(hir_id) || reachable_set.contains(&hir_id);
+
+    let mut def_ids = vec![];
+    for item in krate.items.values() {
+        match item.kind {
+            // Impls have no visibility of their own, and affect coherence and
+            // trait selection downstream no matter where they are.
+            hir::ItemKind::Impl { .. } => def_ids.push(tcx.hir().local_def_id(item.hir_id)),
+            // The layout of a type depends on all of its fields, so private types
+            // stored in the private fields of public types are observable too.
+            hir::ItemKind::Struct(..) | hir::ItemKind::Enum(..) | hir::ItemKind::Union(..) => {
+                def_ids.push(tcx.hir().local_def_id(item.hir_id))
+            }
+            hir::ItemKind::ForeignMod(ref foreign_mod) => {
+                for foreign_item in foreign_mod.items {
+                    if is_interface(foreign_item.hir_id) {
+                        def_ids.push(tcx.hir().local_def_id(foreign_item.hir_id));
+                    }
+                }
+            }
+            _ if is_interface(item.hir_id) => def_ids.push(tcx.hir().local_def_id(item.hir_id)),
+            _ => {}
+        }
+    }
+    for trait_item in krate.trait_items.values() {
+        if is_interface(trait_item.hir_id) {
+            def_ids.push(tcx.hir().local_def_id(trait_item.hir_id));
+        }
+    }
+    for impl_item in krate.impl_items.values() {
+        if is_interface(impl_item.hir_id) {
+            def_ids.push(tcx.hir().local_def_id(impl_item.hir_id));
+        }
+    }
+    // Constants may be evaluated downstream, e.g. when they are used in array lengths
+    // or in the MIR of inlined functions, so their values are always part of the interface.
+    for &def_id in mir_keys {
+        match tcx.def_kind(def_id.to_def_id()) {
+            DefKind::Const | DefKind::AssocConst | DefKind::AnonConst => def_ids.push(def_id),
+            _ => {}
+        }
+    }
+    def_ids.sort_by_cached_key(|&def_id| tcx.def_path_hash(def_id.to_def_id()));
+    def_ids.dedup();
+
+    let mut hcx = tcx.create_stable_hashing_context();
+    let mut hasher = StableHasher::new();
+    tcx.crate_name(LOCAL_CRATE).hash_stable(&mut hcx, &mut hasher);
+    tcx.sess.local_crate_disambiguator().hash_stable(&mut hcx, &mut hasher);
+
+    for local_def_id in def_ids {
+        let def_id = local_def_id.to_def_id();
+        let def_kind = tcx.def_kind(def_id);
+        tcx.def_path_hash(def_id).hash_stable(&mut hcx, &mut hasher);
+        def_kind.hash_stable(&mut hcx, &mut hasher);
+        tcx.visibility(def_id).hash_stable(&mut hcx, &mut hasher);
+        // Spans end up in downstream diagnostics and debuginfo.
+        tcx.def_span(def_id).hash_stable(&mut hcx, &mut hasher);
+        // Stability and deprecation are checked at every downstream use.
+        tcx.lookup_stability(def_id).hash_stable(&mut hcx, &mut hasher);
+        tcx.lookup_const_stability(def_id).hash_stable(&mut hcx, &mut hasher);
+        tcx.lookup_deprecation_entry(def_id).hash_stable(&mut hcx, &mut hasher);
+
+        match def_kind {
+            DefKind::Struct | DefKind::Union | DefKind::Enum => {
+                let adt_def = tcx.adt_def(def_id);
+                adt_def.hash_stable(&mut hcx, &mut hasher);
+                for field in adt_def.all_fields() {
+                    tcx.type_of(field.did).hash_stable(&mut hcx, &mut hasher);
+                }
+            }
+            DefKind::Trait => {
+                tcx.trait_def(def_id).hash_stable(&mut hcx, &mut hasher);
+                tcx.associated_item_def_ids(def_id).hash_stable(&mut hcx, &mut hasher);
+            }
+            DefKind::Impl => {
+                tcx.impl_trait_ref(def_id).hash_stable(&mut hcx, &mut hasher);
+                tcx.impl_polarity(def_id).hash_stable(&mut hcx, &mut hasher);
+            }
+            DefKind::Fn | DefKind::AssocFn => {
+                tcx.fn_sig(def_id).hash_stable(&mut hcx, &mut hasher);
+                // E.g. `#[track_caller]` changes the ABI of the function, and
+                // `#[inline]` decides whether its MIR is available downstream.
+                tcx.codegen_fn_attrs(def_id).hash_stable(&mut hcx, &mut hasher);
+            }
+            DefKind::Static => {
+                tcx.codegen_fn_attrs(def_id).hash_stable(&mut hcx, &mut hasher);
+            }
+            _ => {}
+        }
+
+        let has_type = match def_kind {
+            DefKind::Struct
+            | DefKind::Union
+            | DefKind::Enum
+            | DefKind::TyAlias
+            | DefKind::OpaqueTy
+            | DefKind::ForeignTy
+            | DefKind::Const
+            | DefKind::AssocConst
+            | DefKind::Static
+            | DefKind::Fn
+            | DefKind::AssocFn
+            | DefKind::Impl => true,
+            DefKind::AssocTy => tcx.associated_item(def_id).defaultness.has_value(),
+            _ => false,
+        };
+        if has_type {
+            tcx.type_of(def_id).hash_stable(&mut hcx, &mut hasher);
+        }
+
+        let has_generics = match def_kind {
+            DefKind::Struct
+            | DefKind::Union
+            | DefKind::Enum
+            | DefKind::TyAlias
+            | DefKind::OpaqueTy
+            | DefKind::Trait
+            | DefKind::TraitAlias
+            | DefKind::Fn
+            | DefKind::AssocFn
+            | DefKind::AssocTy
+            | DefKind::AssocConst
+            | DefKind::Impl => true,
+            _ => false,
+        };
+        if has_generics {
+            tcx.generics_of(def_id).hash_stable(&mut hcx, &mut hasher);
+            tcx.predicates_of(def_id).hash_stable(&mut hcx, &mut hasher);
+        }
+
+        // This should be kept in sync with the conditions under which
+        // `encode_optimized_mir` is called for functions.
+        let has_inlinable_mir = mir_keys.contains(&local_def_id)
+            && match def_kind {
+                DefKind::Const | DefKind::AssocConst | DefKind::AnonConst => true,
+                DefKind::Fn | DefKind::AssocFn => {
+                    tcx.generics_of(def_id).requires_monomorphization(tcx)
+                        || tcx.codegen_fn_attrs(def_id).requests_inline()
+                        || tcx.is_const_fn_raw(def_id)
+                }
+                _ => false,
+            };
+        if has_inlinable_mir {
+            tcx.optimized_mir(local_def_id).hash_stable(&mut hcx, &mut hasher);
+            tcx.promoted_mir(local_def_id).hash_stable(&mut hcx, &mut hasher);
+        }
+    }
+
+    for macro_def in krate.exported_macros {
+        tcx.def_path_hash(tcx.hir().local_def_id(macro_def.hir_id).to_def_id())
+            .hash_stable(&mut hcx, &mut hasher);
+        macro_def.ast.hash_stable(&mut hcx, &mut hasher);
+    }
+
+    // With shared generics, downstream crates link against the instances we export
+    // instead of instantiating them, and these depend on the bodies of all functions.
+    tcx.exported_symbols(LOCAL_CRATE).hash_stable(&mut hcx, &mut hasher);
+
+    // Our own interface includes the types and items we re-export from dependencies.
+    let mut deps: Vec<_> = tcx
+        .crates()
+        .iter()
+        .map(|&cnum| {
+            let crate_root = DefId { krate: cnum, index: CRATE_DEF_INDEX };
+            (tcx.def_path_hash(crate_root), tcx.crate_interface_hash(cnum))
+        })
+        .collect();
+    deps.sort_unstable_by_key(|&(def_path_hash, _)| def_path_hash);
+    deps.hash_stable(&mut hcx, &mut hasher);
+
+    let hash: Fingerprint = hasher.finish();
+    Svh::new(hash.to_smaller_hash())
+}
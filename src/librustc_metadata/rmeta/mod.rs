@@ -178,6 +178,9 @@ crate struct CrateRoot<'tcx> {
     triple: TargetTriple,
     extra_filename: String,
     hash: Svh,
+    /// Hash of everything downstream crates can observe about this crate,
+    /// see `encoder::crate_interface_hash`.
+    interface_hash: Svh,
     disambiguator: CrateDisambiguator,
     panic_strategy: PanicStrategy,
     edition: Edition,
@@ -249,6 +252,13 @@ macro_rules! define_tables {
             $($name: TableBuilder<DefIndex, $T>),+
         }
 
+        impl LazyTables<'tcx> {
+            /// Returns the name and encoded size (in bytes) of each table.
+            crate fn byte_sizes(&self) -> Vec<(&'static str, usize)> {
+                vec![$((stringify!($name), self.$name.meta)),+]
+            }
+        }
+
         impl TableBuilders<'tcx> {
             fn encode(&self, buf: &mut Encoder) -> LazyTables<'tcx> {
                 LazyTables {
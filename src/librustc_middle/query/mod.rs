@@ -1099,6 +1099,12 @@ rustc_queries! {
             eval_always
             desc { "looking up the hash a crate" }
         }
+        /// The hash of the parts of a crate that downstream crates can depend on,
+        /// which stays the same when only private implementation details change.
+        query crate_interface_hash(_: CrateNum) -> Svh {
+            eval_always
+            desc { "computing the interface hash of a crate" }
+        }
         query crate_host_hash(_: CrateNum) -> Option<Svh> {
             eval_always
             desc { "looking up the hash of a host version of a crate" }
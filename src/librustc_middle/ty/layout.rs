@@ -11,7 +11,7 @@ use rustc_hir as hir;
 use rustc_hir::lang_items::{GeneratorStateLangItem, PinTypeLangItem};
 use rustc_index::bit_set::BitSet;
 use rustc_index::vec::{Idx, IndexVec};
-use rustc_session::{DataTypeKind, FieldInfo, SizeKind, VariantInfo};
+use rustc_session::{DataTypeKind, FieldInfo, NicheInfo, SizeKind, VariantInfo};
 use rustc_span::symbol::{Ident, Symbol};
 use rustc_span::DUMMY_SP;
 use rustc_target::abi::call::{
@@ -1579,7 +1579,7 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
     fn record_layout_for_printing(&self, layout: TyAndLayout<'tcx>) {
         // If we are running with `-Zprint-type-sizes`, maybe record layouts
         // for dumping later.
-        if self.tcx.sess.opts.debugging_opts.print_type_sizes {
+        if self.tcx.sess.opts.debugging_opts.print_type_sizes.is_some() {
             self.record_layout_for_printing_outlined(layout)
         }
     }
@@ -1592,8 +1592,14 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
             return;
         }
 
+        let largest_niche = layout.largest_niche.as_ref().map(|niche| NicheInfo {
+            offset: niche.offset.bytes(),
+            size: niche.scalar.value.size(self).bytes(),
+            available: niche.available(self),
+        });
+
         // (delay format until we actually need it)
-        let record = |kind, packed, opt_discr_size, variants| {
+        let record = |kind, packed, opt_discr_size, niche_variant, variants| {
             let type_desc = format!("{:?}", layout.ty);
             self.tcx.sess.code_stats.record_type_size(
                 kind,
@@ -1602,6 +1608,8 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
                 layout.size,
                 packed,
                 opt_discr_size,
+                largest_niche.clone(),
+                niche_variant,
                 variants,
             );
         };
@@ -1614,7 +1622,7 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
 
             ty::Closure(..) => {
                 debug!("print-type-size t: `{:?}` record closure", layout.ty);
-                record(DataTypeKind::Closure, false, None, vec![]);
+                record(DataTypeKind::Closure, false, None, None, vec![]);
                 return;
             }
 
@@ -1671,12 +1679,13 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
                         adt_kind.into(),
                         adt_packed,
                         None,
+                        None,
                         vec![build_variant_info(Some(variant_def.ident), &fields, layout)],
                     );
                 } else {
                     // (This case arises for *empty* enums; so give it
                     // zero variants.)
-                    record(adt_kind.into(), adt_packed, None, vec![]);
+                    record(adt_kind.into(), adt_packed, None, None, vec![]);
                 }
             }
 
@@ -1706,6 +1715,12 @@ impl<'tcx> LayoutCx<'tcx, TyCtxt<'tcx>> {
                         TagEncoding::Direct => Some(tag.value.size(self)),
                         _ => None,
                     },
+                    match *tag_encoding {
+                        TagEncoding::Niche { dataful_variant, .. } => {
+                            Some(adt_def.variants[dataful_variant].ident.to_string())
+                        }
+                        _ => None,
+                    },
                     variant_infos,
                 );
             }
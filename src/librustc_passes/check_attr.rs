@@ -70,6 +70,8 @@ impl CheckAttrVisitor<'tcx> {
                 self.check_target_feature(attr, span, target)
             } else if attr.check_name(sym::track_caller) {
                 self.check_track_caller(&attr.span, attrs, span, target)
+            } else if attr.check_name(sym::assert_layout) {
+                self.check_assert_layout(attr, span, target)
             } else if attr.check_name(sym::doc) {
                 self.check_doc_alias(attr)
             } else {
@@ -202,6 +204,21 @@ impl CheckAttrVisitor<'tcx> {
         }
     }
 
+    /// Checks if the `#[assert_layout]` attribute on an `item` is valid. Returns `true` if valid.
+    fn check_assert_layout(&self, attr: &Attribute, span: &Span, target: Target) -> bool {
+        match target {
+            Target::Struct | Target::Enum | Target::Union | Target::TyAlias => true,
+            _ => {
+                self.tcx
+                    .sess
+                    .struct_span_err(attr.span, "attribute should be applied to a type")
+                    .span_label(*span, "not a type")
+                    .emit();
+                false
+            }
+        }
+    }
+
     /// Checks if the `#[target_feature]` attribute on `item` is valid. Returns `true` if valid.
     fn check_target_feature(&self, attr: &Attribute, span: &Span, target: Target) -> bool {
         match target {
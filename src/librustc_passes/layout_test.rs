@@ -1,4 +1,5 @@
-use rustc_ast::ast::Attribute;
+use rustc_ast::ast::{Attribute, LitKind};
+use rustc_errors::pluralize;
 use rustc_hir as hir;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::itemlikevisit::ItemLikeVisitor;
@@ -9,8 +10,8 @@ use rustc_span::symbol::sym;
 use rustc_target::abi::{HasDataLayout, LayoutOf, TargetDataLayout};
 
 pub fn test_layout(tcx: TyCtxt<'_>) {
-    if tcx.features().rustc_attrs {
-        // if the `rustc_attrs` feature is not enabled, don't bother testing layout
+    if tcx.features().rustc_attrs || tcx.features().assert_layout {
+        // if neither `rustc_attrs` nor `assert_layout` is enabled, don't bother testing layout
         tcx.hir().krate().visit_all_item_likes(&mut LayoutTest { tcx });
     }
 }
@@ -31,6 +32,8 @@ impl ItemLikeVisitor<'tcx> for LayoutTest<'tcx> {
                 for attr in self.tcx.get_attrs(item_def_id.to_def_id()).iter() {
                     if attr.check_name(sym::rustc_layout) {
                         self.dump_layout_of(item_def_id, item, attr);
+                    } else if attr.check_name(sym::assert_layout) {
+                        self.assert_layout_of(item_def_id, item, attr);
                     }
                 }
             }
@@ -105,6 +108,64 @@ impl LayoutTest<'tcx> {
             }
         }
     }
+
+    /// Checks the `size = N` and `align = N` expectations of an `#[assert_layout(..)]`
+    /// attribute against the actual layout of the type.
+    fn assert_layout_of(&self, item_def_id: LocalDefId, item: &hir::Item<'tcx>, attr: &Attribute) {
+        let param_env = self.tcx.param_env(item_def_id);
+        let ty = self.tcx.type_of(item_def_id);
+        let ty_layout = match self.tcx.layout_of(param_env.and(ty)) {
+            Ok(ty_layout) => ty_layout,
+            Err(layout_error) => {
+                self.tcx.sess.span_err(item.span, &format!("layout error: {:?}", layout_error));
+                return;
+            }
+        };
+
+        for meta_item in attr.meta_item_list().unwrap_or_default() {
+            let expected = match meta_item.meta_item().and_then(|mi| mi.name_value_literal()) {
+                Some(lit) => match lit.kind {
+                    LitKind::Int(value, _) => value,
+                    _ => {
+                        self.tcx.sess.span_err(lit.span, "expected an integer literal");
+                        continue;
+                    }
+                },
+                None => {
+                    self.tcx.sess.span_err(
+                        meta_item.span(),
+                        "expected `size = N` or `align = N` in `#[assert_layout]`",
+                    );
+                    continue;
+                }
+            };
+            let (what, actual) = match meta_item.name_or_empty() {
+                sym::size => ("size", ty_layout.size.bytes()),
+                sym::align => ("alignment", ty_layout.align.abi.bytes()),
+                name => {
+                    self.tcx.sess.span_err(
+                        meta_item.span(),
+                        &format!("unrecognized field name `{}`", name),
+                    );
+                    continue;
+                }
+            };
+            if u128::from(actual) != expected {
+                self.tcx.sess.span_err(
+                    item.span,
+                    &format!(
+                        "{} of `{}` is {} byte{}, but `#[assert_layout]` expects {} byte{}",
+                        what,
+                        ty,
+                        actual,
+                        pluralize!(actual),
+                        expected,
+                        pluralize!(expected)
+                    ),
+                );
+            }
+        }
+    }
 }
 
 struct UnwrapLayoutCx<'tcx> {
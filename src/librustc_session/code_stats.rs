@@ -1,7 +1,10 @@
+use crate::config::PrintTypeSizes;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lock;
+use rustc_serialize::json::{Json, ToJson};
 use rustc_target::abi::{Align, Size};
 use std::cmp::{self, Ordering};
+use std::collections::BTreeMap;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -26,6 +29,16 @@ pub struct FieldInfo {
     pub align: u64,
 }
 
+/// The largest niche of a type, i.e. the range of invalid values of one of its
+/// scalars that enclosing enums can use to store their discriminant.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NicheInfo {
+    pub offset: u64,
+    pub size: u64,
+    /// The number of invalid values that are available.
+    pub available: u128,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum DataTypeKind {
     Struct,
@@ -42,6 +55,10 @@ pub struct TypeSizeInfo {
     pub overall_size: u64,
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
+    pub largest_niche: Option<NicheInfo>,
+    /// For enums storing their discriminant in a niche of one of their variants,
+    /// the name of that variant.
+    pub niche_variant: Option<String>,
     pub variants: Vec<VariantInfo>,
 }
 
@@ -59,6 +76,8 @@ impl CodeStats {
         overall_size: Size,
         packed: bool,
         opt_discr_size: Option<Size>,
+        largest_niche: Option<NicheInfo>,
+        niche_variant: Option<String>,
         mut variants: Vec<VariantInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
@@ -72,12 +91,14 @@ impl CodeStats {
             overall_size: overall_size.bytes(),
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
+            largest_niche,
+            niche_variant,
             variants,
         };
         self.type_sizes.borrow_mut().insert(info);
     }
 
-    pub fn print_type_sizes(&self) {
+    pub fn print_type_sizes(&self, format: PrintTypeSizes) {
         let type_sizes = self.type_sizes.borrow();
         let mut sorted: Vec<_> = type_sizes.iter().collect();
 
@@ -91,7 +112,17 @@ impl CodeStats {
             }
         });
 
-        for info in &sorted {
+        match format {
+            PrintTypeSizes::Text => Self::print_type_sizes_text(&sorted),
+            PrintTypeSizes::Json => {
+                let types = Json::Array(sorted.iter().map(|info| info.to_json()).collect());
+                println!("{}", types.pretty());
+            }
+        }
+    }
+
+    fn print_type_sizes_text(sorted: &[&TypeSizeInfo]) {
+        for info in sorted {
             println!(
                 "print-type-size type: `{}`: {} bytes, alignment: {} bytes",
                 info.type_description, info.overall_size, info.align
@@ -191,3 +222,88 @@ impl CodeStats {
         }
     }
 }
+
+impl ToJson for TypeSizeInfo {
+    fn to_json(&self) -> Json {
+        let mut obj = BTreeMap::new();
+        obj.insert("type".to_string(), self.type_description.to_json());
+        let kind = match self.kind {
+            DataTypeKind::Struct => "struct",
+            DataTypeKind::Union => "union",
+            DataTypeKind::Enum => "enum",
+            DataTypeKind::Closure => "closure",
+        };
+        obj.insert("kind".to_string(), kind.to_json());
+        obj.insert("size".to_string(), self.overall_size.to_json());
+        obj.insert("align".to_string(), self.align.to_json());
+        obj.insert("packed".to_string(), self.packed.to_json());
+        obj.insert("discriminant_size".to_string(), self.opt_discr_size.to_json());
+        obj.insert("largest_niche".to_string(), self.largest_niche.to_json());
+        obj.insert("niche_variant".to_string(), self.niche_variant.to_json());
+
+        let discr_size = self.opt_discr_size.unwrap_or(0);
+        let mut max_variant_size = discr_size;
+        let variants = self
+            .variants
+            .iter()
+            .map(|variant| {
+                max_variant_size = cmp::max(max_variant_size, variant.size);
+                variant.to_json_with_discr(discr_size, self.packed)
+            })
+            .collect();
+        obj.insert("variants".to_string(), Json::Array(variants));
+        obj.insert(
+            "end_padding".to_string(),
+            self.overall_size.saturating_sub(max_variant_size).to_json(),
+        );
+        Json::Object(obj)
+    }
+}
+
+impl VariantInfo {
+    /// Like the text output, fields are listed by increasing offset, with the
+    /// padding inserted before each of them.
+    fn to_json_with_discr(&self, discr_size: u64, packed: bool) -> Json {
+        let mut obj = BTreeMap::new();
+        obj.insert("name".to_string(), self.name.to_json());
+        let kind = match self.kind {
+            SizeKind::Exact => "exact",
+            SizeKind::Min => "min",
+        };
+        obj.insert("size_kind".to_string(), kind.to_json());
+        obj.insert("size".to_string(), self.size.to_json());
+        obj.insert("align".to_string(), self.align.to_json());
+
+        let mut fields = self.fields.clone();
+        fields.sort_by_key(|f| (f.offset, f.size));
+        let mut min_offset = discr_size;
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let mut obj = BTreeMap::new();
+                obj.insert("name".to_string(), field.name.to_json());
+                obj.insert("offset".to_string(), field.offset.to_json());
+                obj.insert("size".to_string(), field.size.to_json());
+                obj.insert("align".to_string(), field.align.to_json());
+                let padding = if packed { 0 } else { field.offset.saturating_sub(min_offset) };
+                obj.insert("padding".to_string(), padding.to_json());
+                min_offset = cmp::max(min_offset, field.offset + field.size);
+                Json::Object(obj)
+            })
+            .collect();
+        obj.insert("fields".to_string(), Json::Array(fields));
+        Json::Object(obj)
+    }
+}
+
+impl ToJson for NicheInfo {
+    fn to_json(&self) -> Json {
+        let mut obj = BTreeMap::new();
+        obj.insert("offset".to_string(), self.offset.to_json());
+        obj.insert("size".to_string(), self.size.to_json());
+        // Niches of 128-bit scalars can have more values available than fit in JSON
+        // numbers, so this is always emitted as a decimal string.
+        obj.insert("available".to_string(), self.available.to_string().to_json());
+        Json::Object(obj)
+    }
+}
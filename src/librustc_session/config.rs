@@ -103,6 +103,16 @@ pub enum Strip {
     Symbols,
 }
 
+/// The output formats of `-Z print-type-sizes`.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum PrintTypeSizes {
+    /// Human-readable `print-type-size` lines.
+    Text,
+
+    /// A JSON array with one object per type.
+    Json,
+}
+
 /// The different settings that the `-C control-flow-guard` flag can have.
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum CFGuard {
@@ -253,6 +253,8 @@ macro_rules! options {
         pub const parse_cfguard: &str =
             "either a boolean (`yes`, `no`, `on`, `off`, etc), `checks`, or `nochecks`";
         pub const parse_strip: &str = "either `none`, `debuginfo`, or `symbols`";
+        pub const parse_print_type_sizes: &str =
+            "either a boolean (`yes`, `no`, `on`, `off`, etc), `text`, or `json`";
         pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavor::one_of();
         pub const parse_optimization_fuel: &str = "crate=integer";
         pub const parse_unpretty: &str = "`string` or `string=string`";
@@ -495,6 +497,18 @@ macro_rules! options {
             true
         }
 
+        fn parse_print_type_sizes(slot: &mut Option<PrintTypeSizes>, v: Option<&str>) -> bool {
+            match v {
+                Some("y") | Some("yes") | Some("on") | Some("text") | None => {
+                    *slot = Some(PrintTypeSizes::Text)
+                }
+                Some("json") => *slot = Some(PrintTypeSizes::Json),
+                Some("n") | Some("no") | Some("off") => *slot = None,
+                _ => return false,
+            }
+            true
+        }
+
         fn parse_cfguard(slot: &mut CFGuard, v: Option<&str>) -> bool {
             if v.is_some() {
                 let mut bool_arg = None;
@@ -901,6 +915,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "generate JSON tracing data file from LLVM data (default: no)"),
     ls: bool = (false, parse_bool, [UNTRACKED],
         "list the symbols defined by a library crate (default: no)"),
+    ls_json: bool = (false, parse_bool, [UNTRACKED],
+        "print the output of `-Z ls` as JSON (default: no)"),
     macro_backtrace: bool = (false, parse_bool, [UNTRACKED],
         "show macro backtraces (default: no)"),
     merge_functions: Option<MergeFunctions> = (None, parse_merge_functions, [TRACKED],
@@ -963,8 +979,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "print the LLVM optimization passes being run (default: no)"),
     print_mono_items: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "print the result of the monomorphization collection pass"),
-    print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
-        "print layout information for each type encountered (default: no)"),
+    print_type_sizes: Option<PrintTypeSizes> = (None, parse_print_type_sizes, [UNTRACKED],
+        "print layout information for each type encountered, as `text` or `json` (default: no)"),
     profile: bool = (false, parse_bool, [TRACKED],
         "insert profiling code (default: no)"),
     profile_emit: Option<PathBuf> = (None, parse_opt_pathbuf, [TRACKED],
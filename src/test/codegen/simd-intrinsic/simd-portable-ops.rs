@@ -0,0 +1,28 @@
+// compile-flags: -O
+// only-x86_64
+
+#![crate_type = "lib"]
+#![feature(portable_simd)]
+
+use std::simd::{Mask, Simd};
+
+// CHECK-LABEL: @add_f32x4
+#[no_mangle]
+pub fn add_f32x4(a: Simd<f32, 4>, b: Simd<f32, 4>) -> Simd<f32, 4> {
+    // CHECK: fadd <4 x float>
+    a + b
+}
+
+// CHECK-LABEL: @lt_i32x8
+#[no_mangle]
+pub fn lt_i32x8(a: Simd<i32, 8>, b: Simd<i32, 8>) -> Mask<i32, 8> {
+    // CHECK: icmp slt <8 x i32>
+    a.lanes_lt(b)
+}
+
+// CHECK-LABEL: @sum_u8x16
+#[no_mangle]
+pub fn sum_u8x16(a: Simd<u8, 16>) -> u8 {
+    // CHECK: @llvm.experimental.vector.reduce.add.v16i8
+    a.horizontal_sum()
+}
@@ -0,0 +1,38 @@
+pub fn public(x: u32) -> u32 {
+    private(x)
+}
+
+#[cfg(not(changed_body))]
+fn private(x: u32) -> u32 {
+    x + 1
+}
+
+#[cfg(changed_body)]
+fn private(x: u32) -> u32 {
+    x * 2
+}
+
+#[cfg(not(changed_signature))]
+pub fn signature(_: u32) {}
+
+#[cfg(changed_signature)]
+pub fn signature(_: u64) {}
+
+// The variants below only differ in which `cfg`s are enabled, so that
+// the spans of the hashed items stay the same.
+
+pub struct Public(Private);
+
+struct Private(Field);
+
+#[cfg(not(changed_layout))]
+type Field = u32;
+
+#[cfg(changed_layout)]
+type Field = u64;
+
+#[cfg_attr(changed_attrs, track_caller)]
+pub fn attrs() {}
+
+#[cfg_attr(changed_deprecation, deprecated)]
+pub fn deprecation() {}
@@ -0,0 +1,16 @@
+#![crate_type = "rlib"]
+
+pub struct Point {
+    pub x: i32,
+}
+
+pub fn plain() {}
+
+#[inline]
+pub fn inlined() -> u32 {
+    private_helper()
+}
+
+fn private_helper() -> u32 {
+    1
+}
@@ -10,13 +10,13 @@ use std::process::Command;
 fn main() {
     let mut command = Command::new("some-boring-name");
 
-    assert_eq!(format!("{:?}", command), r#""some-boring-name""#);
+    assert_eq!(format!("{:?}", command), "some-boring-name");
 
     command.args(&["1", "2", "3"]);
 
-    assert_eq!(format!("{:?}", command), r#""some-boring-name" "1" "2" "3""#);
+    assert_eq!(format!("{:?}", command), "some-boring-name 1 2 3");
 
     command.arg0("exciting-name");
 
-    assert_eq!(format!("{:?}", command), r#"["some-boring-name"] "exciting-name" "1" "2" "3""#);
+    assert_eq!(format!("{:?}", command), "[some-boring-name] exciting-name 1 2 3");
 }
@@ -0,0 +1,4 @@
+#![crate_type = "lib"]
+
+#[assert_layout(size = 4)] //~ ERROR the `#[assert_layout]` attribute is an experimental feature
+pub struct Foo(u32);
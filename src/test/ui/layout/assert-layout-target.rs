@@ -0,0 +1,15 @@
+#![feature(assert_layout)]
+#![crate_type = "lib"]
+
+#[assert_layout(size = 0)] //~ ERROR attribute should be applied to a type
+pub fn function() {}
+
+#[assert_layout(size = 4)] //~ ERROR attribute should be applied to a type
+pub static STATIC: u32 = 0;
+
+pub struct S;
+
+impl S {
+    #[assert_layout(size = 0)] //~ ERROR attribute should be applied to a type
+    pub fn method() {}
+}
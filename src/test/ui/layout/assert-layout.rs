@@ -0,0 +1,17 @@
+#![feature(assert_layout)]
+#![crate_type = "lib"]
+
+#[assert_layout(size = 8, align = 4)]
+pub struct Ok(u32, u32);
+
+#[assert_layout(size = 16)]
+pub struct TooSmall(u64); //~ ERROR size of `TooSmall` is 8 bytes
+
+#[assert_layout(align = 1)]
+pub struct Misaligned(u16); //~ ERROR alignment of `Misaligned` is 2 bytes
+
+#[assert_layout(size = 1)]
+pub type Niche = Option<bool>;
+
+#[assert_layout(length = 1)] //~ ERROR unrecognized field name `length`
+pub struct Unknown(u8);
@@ -0,0 +1,24 @@
+// compile-flags: -Z print-type-sizes=json
+// build-pass (FIXME(62277): could be check-pass?)
+
+// This file illustrates the JSON output of `-Z print-type-sizes`, including
+// field padding and the niche used by a niche-filling enum.
+
+#![feature(start)]
+
+pub struct Padded {
+    a: u8,
+    b: u32,
+}
+
+pub enum Niche {
+    A(bool),
+    B,
+}
+
+#[start]
+fn start(_: isize, _: *const *const u8) -> isize {
+    let _p: Padded;
+    let _n: Niche;
+    0
+}